@@ -0,0 +1,66 @@
+//! Complex conjugation of triangle matrix elements.
+use std::ops::Neg;
+
+/// An element with a complex conjugate.
+pub trait Conjugate {
+    /// Get the complex conjugate.
+    fn conj(&self) -> Self;
+}
+
+macro_rules! impl_conjugate_real {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conj(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_conjugate_real!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A complex number, `re + im i`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+    /// The real part.
+    pub re: T,
+    /// The imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Create a complex number.
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Conjugate for Complex<T> {
+    fn conj(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_conj() {
+        assert_eq!(2.5f64.conj(), 2.5);
+        assert_eq!((-3i32).conj(), -3);
+        assert_eq!(Complex::new(1.0, 2.0).conj(), Complex::new(1.0, -2.0));
+        assert_eq!(-Complex::new(1, -2), Complex::new(-1, 2));
+    }
+}
@@ -0,0 +1,131 @@
+//! Constant diagonal views of triangle matrices.
+use std::marker::PhantomData;
+
+use crate::{Col, Layout, Row, Triangle};
+
+/// A view of a triangle matrix with the layout, `L`, with a constant diagonal.
+///
+/// Gets the diagonal value for elements where `i == j`, such as `0` for distances,
+/// `1` for correlations or unit triangular factors. Cells outside of the layout
+/// get the fill value, which defaults to `T::default()`. Rows and columns contain
+/// all `n` elements, aligned with their column and row indices.
+pub struct ConstDiagonal<'a, L, M, T> {
+    m: &'a M,
+    diagonal: T,
+    fill: T,
+    layout: PhantomData<L>,
+}
+
+impl<'a, L: Layout, M: Triangle<T>, T> ConstDiagonal<'a, L, M, T> {
+    /// Create a view of `m` with the constant `diagonal`.
+    pub fn new(m: &'a M, diagonal: T) -> Self
+    where
+        T: Default,
+    {
+        ConstDiagonal {
+            m,
+            diagonal,
+            fill: T::default(),
+            layout: PhantomData,
+        }
+    }
+
+    /// Set the value of cells outside of the layout. Defaults to `T::default()`.
+    pub fn fill(mut self, fill: T) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Get the axis length, `n`.
+    pub fn n(&self) -> usize {
+        self.m.n()
+    }
+
+    /// Get a reference to the diagonal value.
+    pub fn diagonal(&self) -> &T {
+        &self.diagonal
+    }
+
+    /// Get a reference to an element, the diagonal value where `i == j`, or the
+    /// fill value outside of the layout.
+    pub fn get_element(&self, i: impl Into<Row>, j: impl Into<Col>) -> &T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
+
+        if i == j {
+            &self.diagonal
+        } else if L::contains(i, j) {
+            L::get_element(self.m, i, j)
+        } else {
+            &self.fill
+        }
+    }
+
+    /// Get an iterator of references to all `n` elements of a row.
+    pub fn get_row(&self, i: impl Into<Row>) -> impl Iterator<Item = &T> {
+        let Row(i) = i.into();
+
+        (0..self.n()).map(move |j| self.get_element(i, j))
+    }
+
+    /// Get an iterator of references to all `n` elements of a column.
+    pub fn get_col(&self, j: impl Into<Col>) -> impl Iterator<Item = &T> {
+        let Col(j) = j.into();
+
+        (0..self.n()).map(move |i| self.get_element(i, j))
+    }
+
+    /// Export the full matrix as a row-major `n × n` collection.
+    pub fn to_dense(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        (0..self.n())
+            .flat_map(|i| self.get_row(i).cloned().collect::<Vec<_>>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{SimpleLower, SimpleLowerTri, SimpleUpper, SymmetricUpper, SymmetricUpperTri, Tri};
+
+    #[test]
+    fn test_symmetric() {
+        let m = Tri::<SymmetricUpper, _>::new(3, vec![1, 2, 3]);
+        let d = SymmetricUpperTri::with_diagonal(&m, 0);
+
+        assert_eq!(*d.get_element(1, 1), 0);
+        assert_eq!(*d.get_element(2, 1), 3);
+        assert_eq!(d.get_row(0).copied().collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(d.get_row(1).copied().collect::<Vec<_>>(), [1, 0, 3]);
+        assert_eq!(d.get_col(2).copied().collect::<Vec<_>>(), [2, 3, 0]);
+    }
+
+    #[test]
+    fn test_unit_lower() {
+        let m = Tri::<SimpleLower, _>::new(3, vec![2.0, 3.0, 4.0]);
+        let d = SimpleLowerTri::with_diagonal(&m, 1.0);
+
+        #[rustfmt::skip]
+        let expected = [
+            1.0, 0.0, 0.0,
+            2.0, 1.0, 0.0,
+            3.0, 4.0, 1.0,
+        ];
+        assert_eq!(d.to_dense(), expected);
+    }
+
+    #[test]
+    fn test_fill() {
+        let m = Tri::<SimpleUpper, _>::new(3, vec![1, 2, 3]);
+        let d = super::ConstDiagonal::<SimpleUpper, _, _>::new(&m, 9).fill(-1);
+
+        assert_eq!(*d.diagonal(), 9);
+        assert_eq!(d.get_row(1).copied().collect::<Vec<_>>(), [-1, 9, 3]);
+        assert_eq!(d.get_col(1).copied().collect::<Vec<_>>(), [1, 9, -1]);
+    }
+}
@@ -0,0 +1,296 @@
+//! Triangle matrix formatting.
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{Layout, Triangle};
+
+/// The default maximum number of rows and columns displayed.
+const DEFAULT_MAX_LEN: usize = 16;
+
+/// A formatter adapter displaying a triangle matrix with the layout, `L`.
+///
+/// Formats rows on separate lines with aligned columns. Cells outside of the
+/// layout, and diagonal cells, display the placeholder. Rows and columns are
+/// truncated with ellipses when `n` exceeds the maximum length. Formatting
+/// precision, such as `{:.3}`, is forwarded to the elements.
+pub struct TriDisplay<'a, L, M, T> {
+    m: &'a M,
+    placeholder: &'a str,
+    labels: bool,
+    max_len: usize,
+    layout: PhantomData<(L, T)>,
+}
+
+impl<'a, L: Layout, M: Triangle<T>, T> TriDisplay<'a, L, M, T> {
+    /// Create a formatter adapter for `m`.
+    pub fn new(m: &'a M) -> Self {
+        TriDisplay {
+            m,
+            placeholder: "",
+            labels: false,
+            max_len: DEFAULT_MAX_LEN,
+            layout: PhantomData,
+        }
+    }
+
+    /// Set the placeholder of cells outside of the layout, and diagonal cells.
+    /// Defaults to a blank cell.
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Set whether to display row and column labels. Defaults to `false`.
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set the maximum number of rows and columns to display before truncating.
+    /// Defaults to `16`.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len.max(2);
+        self
+    }
+
+    /// Get the displayed indices of either axis, with `None` for an ellipsis.
+    fn indices(&self) -> Vec<Option<usize>> {
+        let n = self.m.n();
+
+        if n <= self.max_len {
+            (0..n).map(Some).collect()
+        } else {
+            let head = self.max_len / 2;
+            let tail = self.max_len - head;
+
+            (0..head)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((n - tail..n).map(Some))
+                .collect()
+        }
+    }
+
+    /// Format the cells of the triangle matrix, formatting elements with `fmt_el`.
+    fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        fmt_el: impl Fn(&T, Option<usize>) -> String,
+    ) -> fmt::Result {
+        let indices = self.indices();
+        let precision = f.precision();
+
+        let mut rows = Vec::with_capacity(indices.len() + 1);
+        if self.labels {
+            let mut header = vec![String::new()];
+            header.extend(indices.iter().map(|j| match j {
+                Some(j) => j.to_string(),
+                None => String::from("..."),
+            }));
+            rows.push(header);
+        }
+
+        for i in indices.iter() {
+            let mut row = Vec::with_capacity(indices.len() + 1);
+            if self.labels {
+                row.push(match i {
+                    Some(i) => i.to_string(),
+                    None => String::from("..."),
+                });
+            }
+
+            row.extend(indices.iter().map(|j| match (i, j) {
+                (Some(i), Some(j)) if i != j && L::contains(*i, *j) => {
+                    fmt_el(L::get_element(self.m, *i, *j), precision)
+                }
+                (Some(_), Some(_)) => String::from(self.placeholder),
+                _ => String::from("..."),
+            }));
+            rows.push(row);
+        }
+
+        let cols = rows.first().map_or(0, |row| row.len());
+        let widths = (0..cols)
+            .map(|col| {
+                rows.iter()
+                    .map(|row| row[col].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            })
+            .collect::<Vec<_>>();
+
+        for (k, row) in rows.iter().enumerate() {
+            if k > 0 {
+                writeln!(f)?;
+            }
+
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" ");
+            write!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<L: Layout, M: Triangle<T>, T: fmt::Display> fmt::Display for TriDisplay<'_, L, M, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, |el, precision| match precision {
+            Some(precision) => format!("{:.*}", precision, el),
+            None => format!("{}", el),
+        })
+    }
+}
+
+impl<L: Layout, M: Triangle<T>, T: fmt::Debug> fmt::Debug for TriDisplay<'_, L, M, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, |el, precision| match precision {
+            Some(precision) => format!("{:.*?}", precision, el),
+            None => format!("{:?}", el),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{SimpleLower, SimpleUpper, SymmetricLower, SymmetricUpper, Tri};
+
+    #[test]
+    fn test_display_simple_upper() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(
+            m.display().to_string(),
+            concat!(
+                "  0 1 2 3\n",
+                "    4 5 6\n",
+                "      7 8\n",
+                "        9\n",
+                "",
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_simple_lower() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+
+        let m = Tri::<SimpleLower, _>::new(5, v);
+
+        assert_eq!(
+            m.display().placeholder("-").to_string(),
+            concat!(
+                "- - - - -\n",
+                "0 - - - -\n",
+                "1 2 - - -\n",
+                "3 4 5 - -\n",
+                "6 7 8 9 -",
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_symmetric() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let expected = concat!(
+            "  0 1 2 3\n",
+            "0   4 5 6\n",
+            "1 4   7 8\n",
+            "2 5 7   9\n",
+            "3 6 8 9",
+        );
+        assert_eq!(
+            Tri::<SymmetricUpper, _>::new(5, v.clone())
+                .display()
+                .to_string(),
+            expected
+        );
+
+        let expected = concat!(
+            "  0 1 3 6\n",
+            "0   2 4 7\n",
+            "1 2   5 8\n",
+            "3 4 5   9\n",
+            "6 7 8 9",
+        );
+        assert_eq!(
+            Tri::<SymmetricLower, _>::new(5, v).display().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_display_labels() {
+        let m = Tri::<SimpleUpper, _>::new(4, vec![0, 1, 2, 10, 11, 12]);
+
+        assert_eq!(
+            m.display().labels(true).placeholder(".").to_string(),
+            concat!(
+                "  0 1  2  3\n",
+                "0 . 0  1  2\n",
+                "1 . . 10 11\n",
+                "2 . .  . 12\n",
+                "3 . .  .  .",
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_truncated() {
+        let n = 8;
+        let m = Tri::<SymmetricUpper, _>::new(n, vec![1; 28]);
+
+        assert_eq!(
+            m.display().max_len(4).labels(true).to_string(),
+            concat!(
+                "      0   1 ...   6   7\n",
+                "  0       1 ...   1   1\n",
+                "  1   1     ...   1   1\n",
+                "... ... ... ... ... ...\n",
+                "  6   1   1 ...       1\n",
+                "  7   1   1 ...   1",
+            )
+        );
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let m = Tri::<SimpleUpper, _>::new(3, vec![0.5, 1.0 / 3.0, 2.0]);
+
+        assert_eq!(
+            format!("{:.2}", m.display()),
+            concat!("  0.50 0.33\n", "       2.00\n", "",)
+        );
+        assert_eq!(
+            format!("{:.1?}", m.display()),
+            concat!("  0.5 0.3\n", "      2.0\n", "",)
+        );
+    }
+}
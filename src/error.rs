@@ -0,0 +1,80 @@
+//! Triangle matrix errors.
+use std::fmt;
+
+/// An error returned by triangle matrix operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The axis length, `n`, is zero.
+    EmptyAxis,
+    /// The length of the inner collection does not match the length implied by `n`.
+    InvalidLength {
+        /// The length implied by `n`.
+        expected: usize,
+        /// The length of the inner collection.
+        actual: usize,
+    },
+    /// The axis lengths of two triangle matrices differ.
+    AxisMismatch {
+        /// The axis length of the first triangle matrix.
+        expected: usize,
+        /// The axis length of the second triangle matrix.
+        actual: usize,
+    },
+    /// The length of an operand is incompatible with the dimension of a
+    /// triangle matrix.
+    DimensionMismatch {
+        /// The dimension of the triangle matrix.
+        expected: usize,
+        /// The length of the operand.
+        actual: usize,
+    },
+    /// The diagonal element of row `index` is zero.
+    Singular {
+        /// The row of the zero diagonal element.
+        index: usize,
+    },
+    /// The leading minor of order `index + 1` is not positive definite.
+    NotPositiveDefinite {
+        /// The row of the failed diagonal element.
+        index: usize,
+    },
+    /// An iterative method did not converge.
+    NotConverged {
+        /// The number of iterations performed.
+        iterations: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyAxis => write!(f, "axis length `n` is zero"),
+            Error::InvalidLength { expected, actual } => write!(
+                f,
+                "inner collection has {} elements, expected {}",
+                actual, expected
+            ),
+            Error::AxisMismatch { expected, actual } => {
+                write!(f, "axis length `n` is {}, expected {}", actual, expected)
+            }
+            Error::DimensionMismatch { expected, actual } => write!(
+                f,
+                "operand has {} elements, incompatible with dimension {}",
+                actual, expected
+            ),
+            Error::Singular { index } => write!(f, "diagonal element {} is zero", index),
+            Error::NotPositiveDefinite { index } => {
+                write!(
+                    f,
+                    "leading minor of order {} is not positive definite",
+                    index + 1
+                )
+            }
+            Error::NotConverged { iterations } => {
+                write!(f, "did not converge after {} iterations", iterations)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,314 @@
+//! Undirected weighted graphs over symmetric triangle matrices.
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use crate::{Layout, SymmetricLower, SymmetricUpper, Triangle};
+
+/// A symmetric triangle matrix layout.
+pub trait SymmetricLayout: Layout {}
+
+impl SymmetricLayout for SymmetricUpper {}
+
+impl SymmetricLayout for SymmetricLower {}
+
+/// An undirected graph of `n` nodes, with the edge weights of a symmetric
+/// triangle matrix with the layout, `L`.
+///
+/// Every pair of nodes is an edge by default. Edges are optionally limited to
+/// weights matching a predicate, or weights other than a "no edge" sentinel.
+pub struct TriangleGraph<'a, L, M, T> {
+    m: &'a M,
+    is_edge: Box<dyn Fn(&T) -> bool + 'a>,
+    layout: PhantomData<L>,
+}
+
+impl<'a, L: SymmetricLayout, M: Triangle<T>, T> TriangleGraph<'a, L, M, T> {
+    /// Create a complete graph over `m`.
+    pub fn new(m: &'a M) -> Self {
+        TriangleGraph {
+            m,
+            is_edge: Box::new(|_| true),
+            layout: PhantomData,
+        }
+    }
+
+    /// Set the edges to the pairs with weights matching `f`.
+    pub fn edges_where(mut self, f: impl Fn(&T) -> bool + 'a) -> Self {
+        self.is_edge = Box::new(f);
+        self
+    }
+
+    /// Set the edges to the pairs with weights other than `sentinel`.
+    pub fn no_edge(self, sentinel: T) -> Self
+    where
+        T: PartialEq + 'a,
+    {
+        self.edges_where(move |el| *el != sentinel)
+    }
+
+    /// Get the number of nodes, `n`.
+    pub fn n(&self) -> usize {
+        self.m.n()
+    }
+
+    /// Get a reference to the weight of the edge between `i` and `j`, or `None`
+    /// if there is no edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is not less than `n`.
+    pub fn weight(&self, i: usize, j: usize) -> Option<&T> {
+        self.assert_node(i);
+        self.assert_node(j);
+
+        if i == j {
+            return None;
+        }
+
+        let el = L::get_element(self.m, i, j);
+        (self.is_edge)(el).then_some(el)
+    }
+
+    /// Iterate the neighbours of `i`, and the weights of their edges, in
+    /// ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `n`.
+    pub fn neighbours(&self, i: usize) -> impl Iterator<Item = (usize, &T)> {
+        self.assert_node(i);
+
+        (0..self.n()).filter_map(move |j| self.weight(i, j).map(|el| (j, el)))
+    }
+
+    /// Get the number of neighbours of `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `n`.
+    pub fn degree(&self, i: usize) -> usize {
+        self.assert_node(i);
+
+        self.neighbours(i).count()
+    }
+
+    /// Iterate all edges `(i, j)`, where `i < j`, and their weights.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.n()).flat_map(move |i| {
+            (i + 1..self.n()).filter_map(move |j| self.weight(i, j).map(|el| (i, j, el)))
+        })
+    }
+
+    /// Get the nodes reachable from `start` in breadth-first order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not less than `n`.
+    pub fn bfs(&self, start: usize) -> Vec<usize> {
+        self.assert_node(start);
+
+        let mut visited = vec![false; self.n()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        visited[start] = true;
+
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for (j, _) in self.neighbours(i) {
+                if !visited[j] {
+                    visited[j] = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Get the nodes reachable from `start` in depth-first pre-order, visiting
+    /// neighbours in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is not less than `n`.
+    pub fn dfs(&self, start: usize) -> Vec<usize> {
+        self.assert_node(start);
+
+        let mut visited = vec![false; self.n()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+
+        while let Some(i) = stack.pop() {
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+            order.push(i);
+
+            let neighbours = self.neighbours(i).map(|(j, _)| j).collect::<Vec<_>>();
+            stack.extend(neighbours.into_iter().rev().filter(|j| !visited[*j]));
+        }
+
+        order
+    }
+
+    /// Get the connected component of each node, numbered from `0` in order of
+    /// the lowest node of each component.
+    pub fn connected_components(&self) -> Vec<usize> {
+        let mut components = vec![usize::MAX; self.n()];
+        let mut count = 0;
+
+        for start in 0..self.n() {
+            if components[start] == usize::MAX {
+                self.bfs(start)
+                    .into_iter()
+                    .for_each(|i| components[i] = count);
+                count += 1;
+            }
+        }
+
+        components
+    }
+
+    /// Assert that `i` is a node of the graph.
+    fn assert_node(&self, i: usize) {
+        assert!(
+            i < self.n(),
+            "node {} out of bounds for {} nodes",
+            i,
+            self.n()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{SymmetricLower, SymmetricLowerTri, SymmetricUpper, SymmetricUpperTri, Tri};
+
+    #[test]
+    fn test_neighbours() {
+        // Edges `0 - 1`, `0 - 2`, `1 - 3` and `4 - 5`, where `0` is no edge.
+        #[rustfmt::skip]
+        let v = vec![
+            1, 2, 0, 0, 0,
+               0, 3, 0, 0,
+                  0, 0, 0,
+                     0, 0,
+                        4,
+        ];
+        let m = Tri::<SymmetricUpper, _>::new(6, v);
+        let g = SymmetricUpperTri::graph(&m).no_edge(0);
+
+        assert_eq!(g.neighbours(0).collect::<Vec<_>>(), [(1, &1), (2, &2)]);
+        assert_eq!(g.neighbours(1).collect::<Vec<_>>(), [(0, &1), (3, &3)]);
+        assert_eq!(g.neighbours(5).collect::<Vec<_>>(), [(4, &4)]);
+        assert_eq!(g.weight(2, 0), Some(&2));
+        assert_eq!(g.weight(2, 1), None);
+        assert_eq!(g.weight(2, 2), None);
+        assert_eq!(
+            (0..6).map(|i| g.degree(i)).collect::<Vec<_>>(),
+            [2, 2, 1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_edges() {
+        // Edges `0 - 1`, `0 - 2`, `1 - 3` and `4 - 5`, where `0` is no edge.
+        #[rustfmt::skip]
+        let v = vec![
+            1, 2, 0, 0, 0,
+               0, 3, 0, 0,
+                  0, 0, 0,
+                     0, 0,
+                        4,
+        ];
+        let m = Tri::<SymmetricUpper, _>::new(6, v);
+        let g = SymmetricUpperTri::graph(&m).edges_where(|el| *el > 1);
+
+        assert_eq!(
+            g.edges().collect::<Vec<_>>(),
+            [(0, 2, &2), (1, 3, &3), (4, 5, &4)]
+        );
+
+        let m = Tri::<SymmetricLower, _>::new(3, vec![5, 6, 7]);
+        let g = SymmetricLowerTri::graph(&m);
+
+        assert_eq!(
+            g.edges().collect::<Vec<_>>(),
+            [(0, 1, &5), (0, 2, &6), (1, 2, &7)]
+        );
+    }
+
+    #[test]
+    fn test_traversal() {
+        // Edges `0 - 1`, `0 - 2`, `1 - 3` and `4 - 5`, where `0` is no edge.
+        #[rustfmt::skip]
+        let v = vec![
+            1, 2, 0, 0, 0,
+               0, 3, 0, 0,
+                  0, 0, 0,
+                     0, 0,
+                        4,
+        ];
+        let m = Tri::<SymmetricUpper, _>::new(6, v);
+        let g = SymmetricUpperTri::graph(&m).no_edge(0);
+
+        assert_eq!(g.bfs(0), [0, 1, 2, 3]);
+        assert_eq!(g.dfs(0), [0, 1, 3, 2]);
+        assert_eq!(g.bfs(3), [3, 1, 0, 2]);
+        assert_eq!(g.dfs(5), [5, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "node 3 out of bounds for 3 nodes")]
+    fn test_weight_out_of_bounds() {
+        let m = Tri::<SymmetricUpper, _>::new(3, vec![1, 1, 1]);
+        let _ = SymmetricUpperTri::graph(&m).weight(3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "node 3 out of bounds for 3 nodes")]
+    fn test_neighbours_out_of_bounds() {
+        let m = Tri::<SymmetricUpper, _>::new(3, vec![1, 1, 1]);
+        let _ = SymmetricUpperTri::graph(&m).neighbours(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "node 3 out of bounds for 3 nodes")]
+    fn test_degree_out_of_bounds() {
+        let m = Tri::<SymmetricUpper, _>::new(3, vec![1, 1, 1]);
+        SymmetricUpperTri::graph(&m).degree(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "node 3 out of bounds for 3 nodes")]
+    fn test_bfs_out_of_bounds() {
+        let m = Tri::<SymmetricUpper, _>::new(3, vec![1, 1, 1]);
+        SymmetricUpperTri::graph(&m).bfs(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "node 3 out of bounds for 3 nodes")]
+    fn test_dfs_out_of_bounds() {
+        let m = Tri::<SymmetricUpper, _>::new(3, vec![1, 1, 1]);
+        SymmetricUpperTri::graph(&m).dfs(3);
+    }
+
+    #[test]
+    fn test_connected_components() {
+        // Edges `0 - 1`, `0 - 2`, `1 - 3` and `4 - 5`, where `0` is no edge.
+        #[rustfmt::skip]
+        let v = vec![
+            1, 2, 0, 0, 0,
+               0, 3, 0, 0,
+                  0, 0, 0,
+                     0, 0,
+                        4,
+        ];
+        let m = Tri::<SymmetricUpper, _>::new(6, v);
+        let g = SymmetricUpperTri::graph(&m).no_edge(0);
+
+        assert_eq!(g.connected_components(), [0, 0, 0, 0, 1, 1]);
+    }
+}
@@ -0,0 +1,301 @@
+//! A hollow asymmetric matrix collection.
+use std::ops::{Add, Div, Index, IndexMut, Sub};
+
+use crate::ops::{tri_num, validate_len};
+use crate::{lower, upper, Error, SimpleLower, SimpleUpper, SymmetricUpper, Tri};
+
+/// A hollow matrix without diagonal elements, such as directed pairwise data.
+///
+/// Stores the [`SimpleUpperTri`](crate::SimpleUpperTri) elements, where
+/// `i < j`, followed by the [`SimpleLowerTri`](crate::SimpleLowerTri) elements,
+/// where `j < i`, in a single collection of `2 * tri_num(n - 1)` elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HollowMatrix<T> {
+    n: usize,
+    inner: Vec<T>,
+}
+
+impl<T> HollowMatrix<T> {
+    /// Create a hollow matrix with an axis length of `n` over `inner`,
+    /// containing the upper triangle followed by the lower triangle.
+    pub fn new(n: usize, inner: Vec<T>) -> Self {
+        HollowMatrix { n, inner }
+    }
+
+    /// Create a hollow matrix with an axis length of `n` from the upper and
+    /// lower triangles, validating the length of each triangle.
+    pub fn try_from_halves(n: usize, mut upper: Vec<T>, lower: Vec<T>) -> Result<Self, Error> {
+        validate_len(n, upper.len())?;
+        validate_len(n, lower.len())?;
+
+        upper.extend(lower);
+        Ok(HollowMatrix::new(n, upper))
+    }
+
+    /// Get the axis length, `n`.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Get the inner collection.
+    pub fn inner(&self) -> &[T] {
+        &self.inner
+    }
+
+    /// Consume the hollow matrix, returning the inner collection.
+    pub fn into_inner(self) -> Vec<T> {
+        self.inner
+    }
+
+    /// Get the length of either triangle.
+    fn half_len(&self) -> usize {
+        tri_num(self.n.saturating_sub(1))
+    }
+
+    /// Get the index of the element `(i, j)`, where `i != j`.
+    fn element_index(&self, i: usize, j: usize) -> usize {
+        debug_assert!(i < self.n);
+        debug_assert!(j < self.n);
+        assert!(i != j);
+
+        if i < j {
+            upper::base::get_element_index(i, j - (i + 1), self.n - 1)
+        } else {
+            self.half_len() + lower::base::get_element_index(i - 1, j)
+        }
+    }
+
+    /// Get a reference to the element `(i, j)`, where `i != j`.
+    pub fn get(&self, i: usize, j: usize) -> &T {
+        &self.inner[self.element_index(i, j)]
+    }
+
+    /// Get a mutable reference to the element `(i, j)`, where `i != j`.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> &mut T {
+        let index = self.element_index(i, j);
+        &mut self.inner[index]
+    }
+
+    /// Get the upper triangle, where `i < j`.
+    pub fn upper(&self) -> Tri<SimpleUpper, &[T]> {
+        Tri::new(self.n, &self.inner[..self.half_len()])
+    }
+
+    /// Get the lower triangle, where `j < i`.
+    pub fn lower(&self) -> Tri<SimpleLower, &[T]> {
+        Tri::new(self.n, &self.inner[self.half_len()..])
+    }
+
+    /// Get the mutable upper triangle, where `i < j`.
+    pub fn upper_mut(&mut self) -> Tri<SimpleUpper, &mut [T]> {
+        let len = self.half_len();
+        Tri::new(self.n, &mut self.inner[..len])
+    }
+
+    /// Get the mutable lower triangle, where `j < i`.
+    pub fn lower_mut(&mut self) -> Tri<SimpleLower, &mut [T]> {
+        let len = self.half_len();
+        Tri::new(self.n, &mut self.inner[len..])
+    }
+
+    /// Get an iterator of references to the `n - 1` elements of a row,
+    /// excluding the diagonal.
+    pub fn row(&self, i: usize) -> impl Iterator<Item = &T> {
+        (0..self.n)
+            .filter(move |j| *j != i)
+            .map(move |j| self.get(i, j))
+    }
+
+    /// Get an iterator of references to the `n - 1` elements of a column,
+    /// excluding the diagonal.
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &T> {
+        (0..self.n)
+            .filter(move |i| *i != j)
+            .map(move |i| self.get(i, j))
+    }
+
+    /// Transpose the hollow matrix, swapping the upper and lower triangles.
+    pub fn transpose(mut self) -> Self {
+        for (i, j) in upper::base::iter_triangle_indices(self.n.saturating_sub(1)) {
+            let (i, j) = (i, j + 1);
+            let upper = self.element_index(i, j);
+            let lower = self.element_index(j, i);
+            self.inner.swap(upper, lower);
+        }
+
+        self
+    }
+
+    /// Get the symmetric part, `(A + Aᵀ) / 2`, as a symmetric upper triangle.
+    pub fn symmetric_part(&self) -> Tri<SymmetricUpper, Vec<T>>
+    where
+        T: Copy + Add<Output = T> + Div<Output = T> + From<u8>,
+    {
+        self.upper_pairs(|a, b| (a + b) / T::from(2))
+    }
+
+    /// Get the antisymmetric part, `(A - Aᵀ) / 2`, as a symmetric upper triangle
+    /// of the elements where `i < j`, such as for
+    /// [`SkewUpperTri`](crate::SkewUpperTri).
+    pub fn antisymmetric_part(&self) -> Tri<SymmetricUpper, Vec<T>>
+    where
+        T: Copy + Sub<Output = T> + Div<Output = T> + From<u8>,
+    {
+        self.upper_pairs(|a, b| (a - b) / T::from(2))
+    }
+
+    /// Combine the elements `(i, j)` and `(j, i)` where `i < j` with `f`.
+    fn upper_pairs(&self, f: impl Fn(T, T) -> T) -> Tri<SymmetricUpper, Vec<T>>
+    where
+        T: Copy,
+    {
+        let inner = upper::base::iter_triangle_indices(self.n.saturating_sub(1))
+            .map(|(i, j)| (i, j + 1))
+            .map(|(i, j)| f(*self.get(i, j), *self.get(j, i)))
+            .collect();
+
+        Tri::new(self.n, inner)
+    }
+
+    /// Validate the length of the inner collection against the axis length, `n`.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.n == 0 {
+            return Err(Error::EmptyAxis);
+        }
+
+        let expected = 2 * self.half_len();
+        if self.inner.len() != expected {
+            return Err(Error::InvalidLength {
+                expected,
+                actual: self.inner.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for HollowMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        self.get(i, j)
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for HollowMatrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        self.get_mut(i, j)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{SkewUpperTri, SymmetricUpperTri, Triangle};
+
+    #[test]
+    fn test_get() {
+        // [[ -,  1,  2],
+        //  [ 3,  -,  4],
+        //  [ 5,  6,  -]]
+        let mut m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+
+        assert_eq!(*m.get(0, 1), 1);
+        assert_eq!(*m.get(1, 0), 3);
+        assert_eq!(*m.get(2, 1), 6);
+        assert_eq!(m[(1, 2)], 4);
+
+        m[(2, 0)] = 9;
+        *m.get_mut(0, 2) = 8;
+        assert_eq!(m.inner(), &[1, 8, 4, 3, 9, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_get_diagonal() {
+        let m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+        let _ = m[(1, 1)];
+    }
+
+    #[test]
+    fn test_halves() {
+        // [[ -,  1,  2],
+        //  [ 3,  -,  4],
+        //  [ 5,  6,  -]]
+        let mut m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+
+        assert_eq!(m.upper()[(1, 2)], 4);
+        assert_eq!(m.lower()[(2, 0)], 5);
+
+        m.lower_mut()[(2, 1)] = 7;
+        assert_eq!(m[(2, 1)], 7);
+    }
+
+    #[test]
+    fn test_row_col() {
+        let m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+
+        assert_eq!(m.row(0).copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(m.row(1).copied().collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(m.row(2).copied().collect::<Vec<_>>(), [5, 6]);
+        assert_eq!(m.col(0).copied().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(m.col(2).copied().collect::<Vec<_>>(), [2, 4]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+        let t = m.clone().transpose();
+
+        assert_eq!(t.inner(), &[3, 5, 6, 1, 2, 4]);
+        assert_eq!(t.row(0).copied().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(t.row(1).copied().collect::<Vec<_>>(), [1, 6]);
+        assert_eq!(t.row(2).copied().collect::<Vec<_>>(), [2, 4]);
+        assert_eq!(t.transpose(), m);
+
+        for n in 1..8 {
+            let m = HollowMatrix::new(n, (0..2 * tri_num(n - 1)).collect());
+            let t = m.clone().transpose();
+            for i in 0..n {
+                for j in (0..n).filter(|j| *j != i) {
+                    assert_eq!(t[(j, i)], m[(i, j)]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parts() {
+        let m = HollowMatrix::try_from_halves(3, vec![1.0, 2.0, 4.0], vec![3.0, 6.0, 6.0]).unwrap();
+
+        let symmetric = m.symmetric_part();
+        assert_eq!(symmetric.inner(), &[2.0, 4.0, 5.0]);
+        assert_eq!(*SymmetricUpperTri::get_element(&symmetric, 2, 0), 4.0);
+
+        let antisymmetric = m.antisymmetric_part();
+        assert_eq!(antisymmetric.inner(), &[-1.0, -2.0, -1.0]);
+        assert_eq!(SkewUpperTri::get_element(&antisymmetric, 2, 1), 1.0);
+    }
+
+    #[test]
+    fn test_validate() {
+        let m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+        assert_eq!(m.validate(), Ok(()));
+        assert_eq!(
+            HollowMatrix::try_from_halves(3, vec![1, 2], vec![3, 4, 5]),
+            Err(Error::InvalidLength {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            HollowMatrix::new(3, vec![1, 2, 3, 4, 5]).validate(),
+            Err(Error::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+    }
+}
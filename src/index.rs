@@ -0,0 +1,124 @@
+//! Triangle matrix index types.
+
+/// A row index, `i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Row(pub usize);
+
+/// A column index, `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Col(pub usize);
+
+/// A pair of row and column indices, `(i, j)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pair {
+    /// The row index.
+    pub i: Row,
+    /// The column index.
+    pub j: Col,
+}
+
+impl From<usize> for Row {
+    fn from(i: usize) -> Self {
+        Row(i)
+    }
+}
+
+impl From<Row> for usize {
+    fn from(i: Row) -> Self {
+        i.0
+    }
+}
+
+impl From<usize> for Col {
+    fn from(j: usize) -> Self {
+        Col(j)
+    }
+}
+
+impl From<Col> for usize {
+    fn from(j: Col) -> Self {
+        j.0
+    }
+}
+
+impl Pair {
+    /// Create a pair of indices.
+    pub fn new(i: impl Into<Row>, j: impl Into<Col>) -> Self {
+        Pair {
+            i: i.into(),
+            j: j.into(),
+        }
+    }
+
+    /// Create a pair of indices in the upper triangle, where `i < j`, from
+    /// indices `a` and `b` in any order.
+    ///
+    /// Canonicalises the indices of symmetric upper triangle layouts.
+    pub fn upper(a: usize, b: usize) -> Self {
+        Pair::new(a.min(b), a.max(b))
+    }
+
+    /// Create a pair of indices in the lower triangle, where `j < i`, from
+    /// indices `a` and `b` in any order.
+    ///
+    /// Canonicalises the indices of symmetric lower triangle layouts.
+    pub fn lower(a: usize, b: usize) -> Self {
+        Pair::new(a.max(b), a.min(b))
+    }
+
+    /// Get the transposed pair, `(j, i)`.
+    pub fn transpose(self) -> Self {
+        Pair::new(self.j.0, self.i.0)
+    }
+}
+
+impl From<(usize, usize)> for Pair {
+    fn from((i, j): (usize, usize)) -> Self {
+        Pair::new(i, j)
+    }
+}
+
+impl From<(Row, Col)> for Pair {
+    fn from((i, j): (Row, Col)) -> Self {
+        Pair { i, j }
+    }
+}
+
+impl From<Pair> for (usize, usize) {
+    fn from(pair: Pair) -> Self {
+        (pair.i.0, pair.j.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_pair_new() {
+        assert_eq!(
+            Pair::new(1, 3),
+            Pair {
+                i: Row(1),
+                j: Col(3)
+            }
+        );
+        assert_eq!(Pair::new(Row(1), Col(3)), Pair::from((1, 3)));
+        assert_eq!(Pair::from((Row(3), Col(1))), Pair::new(3, 1));
+        assert_eq!(<(usize, usize)>::from(Pair::new(3, 1)), (3, 1));
+    }
+
+    #[test]
+    fn test_pair_canonical() {
+        assert_eq!(Pair::upper(1, 3), Pair::new(1, 3));
+        assert_eq!(Pair::upper(3, 1), Pair::new(1, 3));
+        assert_eq!(Pair::lower(1, 3), Pair::new(3, 1));
+        assert_eq!(Pair::lower(3, 1), Pair::new(3, 1));
+    }
+
+    #[test]
+    fn test_pair_transpose() {
+        assert_eq!(Pair::new(1, 3).transpose(), Pair::new(3, 1));
+    }
+}
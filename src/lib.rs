@@ -30,6 +30,117 @@
 //!     }
 //! }
 //! ```
+//! The length of the inner collection must equal `tri_num(n - 1)` for an axis
+//! length, `n`, greater than zero. Each triangle matrix type provides `validate`
+//! and `validated` to check this, returning an [`Error`] for an empty axis or a
+//! mismatched length.
+//!
+//! ## Index operators ([`Tri`])
+//!
+//! [`Tri`] wraps a collection with a layout marker ([`SimpleUpper`],
+//! [`SymmetricUpper`], [`SimpleLower`] or [`SymmetricLower`]), and dispatches
+//! `Index<(usize, usize)>` and `IndexMut<(usize, usize)>` to the `get_element`
+//! and `get_element_mut` methods of the layout. [`Tri::map`], [`Tri::zip_with`]
+//! and [`Tri::apply`] transform elements while preserving the layout, and owned
+//! collections implement element-wise `Add`, `Sub` and `Neg`, and scalar `Mul`.
+//!
+//! ```
+//! use crate::triangle_matrix::{SymmetricUpper, Tri};
+//!
+//! let mut m = Tri::<SymmetricUpper, _>::try_new(4, vec![0, 1, 2, 3, 4, 5]).unwrap();
+//! m[(2, 1)] = 10;
+//!
+//! assert_eq!(m[(0, 2)], 1);
+//! assert_eq!(m[(1, 2)], 10);
+//! ```
+//!
+//! ## Row and column indices ([`Row`], [`Col`], [`Pair`])
+//!
+//! Accessors accept plain `usize` indices, or [`Row`] and [`Col`] indices to
+//! prevent swapping `i` and `j`. [`Pair`] holds both, with [`Pair::upper`] and
+//! [`Pair::lower`] canonicalising indices of symmetric layouts. A [`Pair`], or a
+//! `(usize, usize)` tuple, is accepted by `get_pair` and `get_pair_mut`, and by
+//! the index operators of [`Tri`].
+//!
+//! ```
+//! use crate::triangle_matrix::{Col, Pair, Row, SimpleUpper, SimpleUpperTri, Tri};
+//!
+//! let m = Tri::<SimpleUpper, _>::try_new(4, vec![0, 1, 2, 3, 4, 5]).unwrap();
+//!
+//! assert_eq!(*m.get_element(Row(1), Col(2)), 3);
+//! assert_eq!(m[Pair::upper(2, 1)], 3);
+//! assert_eq!(*m.get_pair(Pair::upper(2, 1)), 3);
+//! ```
+//!
+//! ```compile_fail
+//! # use crate::triangle_matrix::{Col, Row, SimpleUpper, SimpleUpperTri, Tri};
+//! # let m = Tri::<SimpleUpper, _>::try_new(4, vec![0, 1, 2, 3, 4, 5]).unwrap();
+//! m.get_element(Col(2), Row(1));
+//! ```
+//!
+//! ## Formatting ([`TriDisplay`])
+//!
+//! Each triangle matrix type provides `display`, a formatter adapter rendering
+//! rows with aligned columns. Placeholders, row and column labels, and
+//! truncation for large `n` are configurable, and precision is forwarded to
+//! the elements.
+//!
+//! ```
+//! use crate::triangle_matrix::{SymmetricUpper, Tri};
+//!
+//! let m = Tri::<SymmetricUpper, _>::try_new(3, vec![0.5, 1.25, 2.0]).unwrap();
+//!
+//! assert_eq!(
+//!     format!("{:.1}", m.display().placeholder("-")),
+//!     "  - 0.5 1.2\n0.5   - 2.0\n1.2 2.0   -"
+//! );
+//! ```
+//!
+//! ## Diagonal views ([`ConstDiagonal`])
+//!
+//! Each triangle matrix type provides `with_diagonal`, a view with a constant
+//! diagonal value, such as `0` for distances or `1` for correlations. Rows and
+//! columns of the view contain all `n` elements, aligned with their indices.
+//!
+//! ```
+//! use crate::triangle_matrix::{SymmetricUpper, SymmetricUpperTri, Tri};
+//!
+//! let m = Tri::<SymmetricUpper, _>::try_new(3, vec![1, 2, 3]).unwrap();
+//! let d = m.with_diagonal(0);
+//!
+//! assert_eq!(d.get_row(1).copied().collect::<Vec<_>>(), [1, 0, 3]);
+//! ```
+//!
+//! ## Graphs ([`TriangleGraph`])
+//!
+//! The symmetric types provide `graph`, an undirected graph with the elements
+//! as edge weights. Edges are optionally limited by a predicate or a "no edge"
+//! sentinel, and the graph provides neighbours, degrees, edge iteration,
+//! breadth-first and depth-first traversal, and connected components.
+//!
+//! ```
+//! use crate::triangle_matrix::{SymmetricUpper, SymmetricUpperTri, Tri};
+//!
+//! let m = Tri::<SymmetricUpper, _>::try_new(4, vec![1, 0, 0, 0, 0, 1]).unwrap();
+//! let g = m.graph().no_edge(0);
+//!
+//! assert_eq!(g.neighbours(0).collect::<Vec<_>>(), [(1, &1)]);
+//! assert_eq!(g.connected_components(), [0, 0, 1, 1]);
+//! ```
+//!
+//! ## Statistics ([`TriangleStats`])
+//!
+//! [`TriangleStats`] provides the sum, mean, extrema, variance, quantiles and
+//! histogram of the stored elements of any triangle matrix, converting numeric
+//! elements with [`stats::ToF64`]. The sum and extrema are named `element_sum`,
+//! `element_min` and `element_max` to avoid clashing with [`Iterator`] and
+//! [`Ord`] methods. The symmetric types additionally provide `row_sums`,
+//! counting each element in both of its rows.
+//! [`stats::CovarianceAccumulator`] streams observations into a packed
+//! covariance or correlation matrix without storing the observations, and
+//! [`stats::correlation`] builds Pearson, Spearman and Kendall correlation
+//! matrices from column-major data.
+//!
 //! ## Triangle matrix types
 //!
 //! For all types, the indices `i` and `j` refer to rows and columns respectively.
@@ -236,14 +347,94 @@
 //! assert_eq!(*m.get_element(3, 1), 4);
 //! assert_eq!(*m.get_element(3, 2), 5);
 //! ```
+//!
+//! ### Skew-Symmetric Triangles ([`SkewUpperTri`], [`SkewLowerTri`])
+//!
+//! Indexing operations for skew-symmetric triangle matrices with no diagonal
+//! elements, where the pair `(j, i)` is the negation of the pair `(i, j)`.
+//! Elements are returned by value, negated when the indices are swapped from the
+//! stored orientation, and [`SkewUpperTriMut`] and [`SkewLowerTriMut`] setters
+//! store the canonical orientation.
+//!
+//! ```
+//! use crate::triangle_matrix::{SkewUpperTri, SkewUpperTriMut, SymmetricUpper, Tri};
+//!
+//! let mut m = Tri::<SymmetricUpper, _>::new(3, vec![0; 3]);
+//! m.set_element(2, 0, 5);
+//!
+//! assert_eq!(m.get_element(0, 2), -5);
+//! assert_eq!(m.get_row(2).collect::<Vec<_>>(), [5, 0]);
+//! ```
+//!
+//! ### Hermitian Triangles ([`HermitianUpperTri`], [`HermitianLowerTri`])
+//!
+//! Indexing operations for Hermitian triangle matrices with no diagonal
+//! elements, where the pair `(j, i)` is the complex conjugate of the pair
+//! `(i, j)`. Elements implement [`Conjugate`], which is the identity for real
+//! numbers, and [`Complex`] provides a complex element type. The real diagonal
+//! of a Hermitian matrix is not stored, and must be kept separately.
+//!
+//! ```
+//! use crate::triangle_matrix::{Complex, HermitianLowerTri, HermitianLowerTriMut, SymmetricLower, Tri};
+//!
+//! let mut m = Tri::<SymmetricLower, _>::new(3, vec![Complex::default(); 3]);
+//! m.set_element(0, 2, Complex::new(1.0, 2.0));
+//!
+//! assert_eq!(m.get_element(2, 0), Complex::new(1.0, -2.0));
+//! assert_eq!(m.get_element(0, 2), Complex::new(1.0, 2.0));
+//! ```
+//!
+//! ### Hollow Matrix ([`HollowMatrix`])
+//!
+//! A matrix with no diagonal elements that is not symmetric, storing a simple
+//! upper triangle followed by a simple lower triangle in one collection. Allows
+//! getting any element where `i != j`, and extracting the symmetric and
+//! antisymmetric parts into the symmetric upper layout.
+//!
+//! ```
+//! use crate::triangle_matrix::HollowMatrix;
+//!
+//! let m = HollowMatrix::try_from_halves(3, vec![1, 2, 4], vec![3, 5, 6]).unwrap();
+//!
+//! assert_eq!(m[(0, 1)], 1);
+//! assert_eq!(m[(1, 0)], 3);
+//! assert_eq!(m.transpose()[(0, 1)], 3);
+//! ```
+
+mod complex;
 mod def;
+mod diagonal;
+mod display;
+mod error;
+mod graph;
+mod hollow;
+mod index;
+mod tri;
 
+pub mod linalg;
 pub mod lower;
 pub mod upper;
 
 pub mod ops;
+pub mod stats;
 
+pub use complex::{Complex, Conjugate};
 pub use def::{Triangle, TriangleMut};
+pub use diagonal::ConstDiagonal;
+pub use display::TriDisplay;
+pub use error::Error;
+pub use graph::{SymmetricLayout, TriangleGraph};
+pub use hollow::HollowMatrix;
+pub use index::{Col, Pair, Row};
+pub use tri::{Layout, SimpleLower, SimpleUpper, SymmetricLower, SymmetricUpper, Tri};
+
+pub use stats::TriangleStats;
 
-pub use lower::{SimpleLowerTri, SimpleLowerTriMut, SymmetricLowerTri, SymmetricLowerTriMut};
-pub use upper::{SimpleUpperTri, SimpleUpperTriMut, SymmetricUpperTri, SymmetricUpperTriMut};
+pub use lower::{
+    HermitianLowerTri, HermitianLowerTriMut, SimpleLowerTri, SimpleLowerTriMut, SkewLowerTri,
+    SkewLowerTriMut, SymmetricLowerTri, SymmetricLowerTriMut,
+};
+pub use upper::{
+    HermitianUpperTri, HermitianUpperTriMut, SimpleUpperTri, SimpleUpperTriMut, SkewUpperTri,
+    SkewUpperTriMut, SymmetricUpperTri, SymmetricUpperTriMut,
+};
@@ -0,0 +1,362 @@
+//! Symmetric packed matrix-vector and rank update kernels.
+//!
+//! Packed equivalents of the BLAS level 2 `spmv`, `spr` and `spr2` routines,
+//! and a `syrk` style builder. Each kernel traverses the contiguous rows of the
+//! packed triangle once, applying each element to both of its mirrored
+//! positions.
+use super::{validate_cols_len, validate_vec_len};
+use crate::ops::{tri_num, validate_packed_len};
+use crate::{lower, upper, Error};
+
+/// Scale `y` by `beta`, setting `y` to zero if `beta` is zero.
+fn scale(beta: f64, y: &mut [f64]) {
+    if beta == 0.0 {
+        y.iter_mut().for_each(|el| *el = 0.0);
+    } else if beta != 1.0 {
+        y.iter_mut().for_each(|el| *el *= beta);
+    }
+}
+
+/// Calculate `y = alpha A x + beta y`, where `A` is an upper packed symmetric
+/// triangle.
+pub fn spmv_upper(
+    a: &[f64],
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    beta: f64,
+    y: &mut [f64],
+) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+    validate_vec_len(n, x.len())?;
+    validate_vec_len(n, y.len())?;
+
+    scale(beta, y);
+    for i in 0..n {
+        let row = &a[upper::base::get_row_start_index(i, n)..][..n - i];
+        let xi = alpha * x[i];
+
+        let mut sum = row[0] * x[i];
+        for (k, el) in row.iter().enumerate().skip(1) {
+            sum += el * x[i + k];
+            y[i + k] += el * xi;
+        }
+        y[i] += alpha * sum;
+    }
+
+    Ok(())
+}
+
+/// Calculate `y = alpha A x + beta y`, where `A` is a lower packed symmetric
+/// triangle.
+pub fn spmv_lower(
+    a: &[f64],
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    beta: f64,
+    y: &mut [f64],
+) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+    validate_vec_len(n, x.len())?;
+    validate_vec_len(n, y.len())?;
+
+    scale(beta, y);
+    for i in 0..n {
+        let row = &a[lower::base::get_row_start_index(i)..][..=i];
+        let xi = alpha * x[i];
+
+        let mut sum = row[i] * x[i];
+        for (j, el) in row[..i].iter().enumerate() {
+            sum += el * x[j];
+            y[j] += el * xi;
+        }
+        y[i] += alpha * sum;
+    }
+
+    Ok(())
+}
+
+/// Calculate the rank 1 update `A = alpha x xᵀ + A`, where `A` is an upper
+/// packed symmetric triangle.
+pub fn spr_upper(a: &mut [f64], n: usize, alpha: f64, x: &[f64]) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+    validate_vec_len(n, x.len())?;
+
+    for i in 0..n {
+        let row = &mut a[upper::base::get_row_start_index(i, n)..][..n - i];
+        let xi = alpha * x[i];
+
+        row.iter_mut()
+            .zip(x[i..].iter())
+            .for_each(|(el, xj)| *el += xi * xj);
+    }
+
+    Ok(())
+}
+
+/// Calculate the rank 1 update `A = alpha x xᵀ + A`, where `A` is a lower
+/// packed symmetric triangle.
+pub fn spr_lower(a: &mut [f64], n: usize, alpha: f64, x: &[f64]) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+    validate_vec_len(n, x.len())?;
+
+    for i in 0..n {
+        let row = &mut a[lower::base::get_row_start_index(i)..][..=i];
+        let xi = alpha * x[i];
+
+        row.iter_mut()
+            .zip(x[..=i].iter())
+            .for_each(|(el, xj)| *el += xi * xj);
+    }
+
+    Ok(())
+}
+
+/// Calculate the rank 2 update `A = alpha x yᵀ + alpha y xᵀ + A`, where `A` is
+/// an upper packed symmetric triangle.
+pub fn spr2_upper(a: &mut [f64], n: usize, alpha: f64, x: &[f64], y: &[f64]) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+    validate_vec_len(n, x.len())?;
+    validate_vec_len(n, y.len())?;
+
+    for i in 0..n {
+        let row = &mut a[upper::base::get_row_start_index(i, n)..][..n - i];
+        let (xi, yi) = (alpha * x[i], alpha * y[i]);
+
+        row.iter_mut()
+            .zip(x[i..].iter().zip(y[i..].iter()))
+            .for_each(|(el, (xj, yj))| *el += xi * yj + yi * xj);
+    }
+
+    Ok(())
+}
+
+/// Calculate the rank 2 update `A = alpha x yᵀ + alpha y xᵀ + A`, where `A` is
+/// a lower packed symmetric triangle.
+pub fn spr2_lower(a: &mut [f64], n: usize, alpha: f64, x: &[f64], y: &[f64]) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+    validate_vec_len(n, x.len())?;
+    validate_vec_len(n, y.len())?;
+
+    for i in 0..n {
+        let row = &mut a[lower::base::get_row_start_index(i)..][..=i];
+        let (xi, yi) = (alpha * x[i], alpha * y[i]);
+
+        row.iter_mut()
+            .zip(x[..=i].iter().zip(y[..=i].iter()))
+            .for_each(|(el, (xj, yj))| *el += xi * yj + yi * xj);
+    }
+
+    Ok(())
+}
+
+/// Get the rows of a dense row-major matrix of `n` rows.
+fn dense_rows(data: &[f64], n: usize) -> Result<Vec<&[f64]>, Error> {
+    if n == 0 {
+        return Err(Error::EmptyAxis);
+    }
+    validate_cols_len(n, data.len())?;
+
+    let k = data.len() / n;
+    Ok((0..n).map(|i| &data[i * k..(i + 1) * k]).collect())
+}
+
+/// Calculate `A Aᵀ` as an upper packed symmetric triangle, where `A` is the
+/// dense row-major matrix, `data`, of `n` rows.
+pub fn syrk_upper(data: &[f64], n: usize) -> Result<Vec<f64>, Error> {
+    let rows = dense_rows(data, n)?;
+
+    let mut c = Vec::with_capacity(tri_num(n));
+    for i in 0..n {
+        c.extend(rows[i..].iter().map(|row| {
+            rows[i]
+                .iter()
+                .zip(row.iter())
+                .map(|(a, b)| a * b)
+                .sum::<f64>()
+        }));
+    }
+
+    Ok(c)
+}
+
+/// Calculate `A Aᵀ` as a lower packed symmetric triangle, where `A` is the
+/// dense row-major matrix, `data`, of `n` rows.
+pub fn syrk_lower(data: &[f64], n: usize) -> Result<Vec<f64>, Error> {
+    let rows = dense_rows(data, n)?;
+
+    let mut c = Vec::with_capacity(tri_num(n));
+    for i in 0..n {
+        c.extend(rows[..=i].iter().map(|row| {
+            rows[i]
+                .iter()
+                .zip(row.iter())
+                .map(|(a, b)| a * b)
+                .sum::<f64>()
+        }));
+    }
+
+    Ok(c)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[rustfmt::skip]
+    const DENSE: [[f64; 3]; 3] = [
+        [4.0, 2.0, -2.0],
+        [2.0, 5.0, 1.0],
+        [-2.0, 1.0, 6.0],
+    ];
+
+    #[rustfmt::skip]
+    const UPPER: [f64; 6] = [
+        4.0, 2.0, -2.0,
+             5.0,  1.0,
+                   6.0,
+    ];
+
+    #[rustfmt::skip]
+    const LOWER: [f64; 6] = [
+         4.0,
+         2.0, 5.0,
+        -2.0, 1.0, 6.0,
+    ];
+
+    /// Calculate `alpha A x + beta y` for the dense matrix `A`.
+    fn dense_mv(alpha: f64, x: &[f64], beta: f64, y: &[f64]) -> Vec<f64> {
+        DENSE
+            .iter()
+            .zip(y)
+            .map(|(row, y)| alpha * row.iter().zip(x).map(|(a, x)| a * x).sum::<f64>() + beta * y)
+            .collect()
+    }
+
+    #[test]
+    fn test_spmv() {
+        let x = [1.0, -2.0, 0.5];
+        let y = [3.0, 1.0, -1.0];
+        let expected = dense_mv(2.0, &x, 0.5, &y);
+
+        let mut z = y;
+        spmv_upper(&UPPER, 3, 2.0, &x, 0.5, &mut z).unwrap();
+        assert_close(&z, &expected);
+
+        let mut z = y;
+        spmv_lower(&LOWER, 3, 2.0, &x, 0.5, &mut z).unwrap();
+        assert_close(&z, &expected);
+    }
+
+    #[test]
+    fn test_spmv_zero_beta() {
+        let x = [1.0, -2.0, 0.5];
+        let mut y = [f64::NAN; 3];
+        spmv_lower(&LOWER, 3, 1.0, &x, 0.0, &mut y).unwrap();
+
+        assert_close(&y, &dense_mv(1.0, &x, 0.0, &[0.0; 3]));
+    }
+
+    #[test]
+    fn test_spmv_dimension_mismatch() {
+        let mut y = [0.0; 3];
+
+        assert_eq!(
+            spmv_upper(&UPPER, 3, 1.0, &[1.0; 2], 0.0, &mut y),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_spr() {
+        let x = [1.0, -2.0, 0.5];
+
+        let mut a = UPPER;
+        spr_upper(&mut a, 3, 2.0, &x).unwrap();
+        #[rustfmt::skip]
+        assert_close(&a, &[
+            6.0, -2.0, -1.0,
+                 13.0, -1.0,
+                        6.5,
+        ]);
+
+        let mut a = LOWER;
+        spr_lower(&mut a, 3, 2.0, &x).unwrap();
+        #[rustfmt::skip]
+        assert_close(&a, &[
+             6.0,
+            -2.0, 13.0,
+            -1.0, -1.0, 6.5,
+        ]);
+    }
+
+    #[test]
+    fn test_spr2() {
+        let x = [1.0, -2.0, 0.5];
+        let y = [0.0, 1.0, 2.0];
+
+        // `x yᵀ + y xᵀ`
+        #[rustfmt::skip]
+        let update = [
+            [0.0, 1.0, 2.0],
+            [1.0, -4.0, -3.5],
+            [2.0, -3.5, 2.0],
+        ];
+
+        let mut a = UPPER;
+        spr2_upper(&mut a, 3, 1.0, &x, &y).unwrap();
+        for (i, j) in upper::base::iter_triangle_indices(3) {
+            let el = a[upper::base::get_element_index(i, j - i, 3)];
+            assert_eq!(el, DENSE[i][j] + update[i][j]);
+        }
+
+        let mut a = LOWER;
+        spr2_lower(&mut a, 3, 1.0, &x, &y).unwrap();
+        for (i, j) in lower::base::iter_triangle_indices(3) {
+            let el = a[lower::base::get_element_index(i, j)];
+            assert_eq!(el, DENSE[i][j] + update[i][j]);
+        }
+    }
+
+    #[test]
+    fn test_syrk() {
+        #[rustfmt::skip]
+        let data = [
+            1.0, 2.0,
+            0.0, -1.0,
+            3.0, 1.0,
+        ];
+
+        #[rustfmt::skip]
+        assert_close(&syrk_upper(&data, 3).unwrap(), &[
+            5.0, -2.0, 5.0,
+                  1.0, -1.0,
+                       10.0,
+        ]);
+        #[rustfmt::skip]
+        assert_close(&syrk_lower(&data, 3).unwrap(), &[
+             5.0,
+            -2.0,  1.0,
+             5.0, -1.0, 10.0,
+        ]);
+        assert_eq!(
+            syrk_lower(&data, 4),
+            Err(Error::DimensionMismatch {
+                expected: 4,
+                actual: 6
+            })
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Preconditioned conjugate gradient solves of symmetric systems.
+//!
+//! The operator, `A`, is a packed symmetric triangle of `tri_num(n)` elements
+//! including the diagonal, such as the output of [`syrk_upper`] or
+//! [`syrk_lower`]. The matrix-vector product is [`spmv_upper`] or
+//! [`spmv_lower`].
+//!
+//! [`syrk_upper`]: super::blas::syrk_upper
+//! [`syrk_lower`]: super::blas::syrk_lower
+use super::blas::{spmv_lower, spmv_upper};
+use super::validate_vec_len;
+use crate::ops::validate_packed_len;
+use crate::{lower, upper, Error};
+
+/// The default relative residual tolerance.
+const DEFAULT_TOL: f64 = 1e-10;
+/// The default maximum number of iterations.
+const DEFAULT_MAX_ITER: usize = 1000;
+
+/// A preconditioner, `M`, approximating `A`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Preconditioner {
+    /// No preconditioning, `M = I`.
+    #[default]
+    Identity,
+    /// Jacobi preconditioning, `M = diag(A)`.
+    Jacobi,
+}
+
+/// The convergence of a conjugate gradient solve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convergence {
+    /// Whether the relative residual is within the tolerance.
+    pub converged: bool,
+    /// The number of iterations performed.
+    pub iterations: usize,
+    /// The relative residual, `‖b - A x‖ / ‖b‖`.
+    pub residual: f64,
+}
+
+/// A preconditioned conjugate gradient solver of `A x = b`, where `A` is
+/// symmetric positive definite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConjugateGradient {
+    tol: f64,
+    max_iter: usize,
+    preconditioner: Preconditioner,
+}
+
+impl Default for ConjugateGradient {
+    fn default() -> Self {
+        ConjugateGradient::new()
+    }
+}
+
+impl ConjugateGradient {
+    /// Create a conjugate gradient solver.
+    pub fn new() -> Self {
+        ConjugateGradient {
+            tol: DEFAULT_TOL,
+            max_iter: DEFAULT_MAX_ITER,
+            preconditioner: Preconditioner::Identity,
+        }
+    }
+
+    /// Set the relative residual tolerance. Defaults to `1e-10`.
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Set the maximum number of iterations. Defaults to `1000`.
+    pub fn max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Set the preconditioner. Defaults to [`Preconditioner::Identity`].
+    pub fn preconditioner(mut self, preconditioner: Preconditioner) -> Self {
+        self.preconditioner = preconditioner;
+        self
+    }
+
+    /// Solve `A x = b`, where `A` is the upper packed symmetric triangle, `a`,
+    /// starting from and overwriting `x`.
+    ///
+    /// Stops with the last finite iterate, reporting no convergence, if a
+    /// search direction, `p`, has `pᵀ A p <= 0`, indicating that `A` is not
+    /// positive definite.
+    pub fn solve_upper(
+        &self,
+        a: &[f64],
+        n: usize,
+        b: &[f64],
+        x: &mut [f64],
+    ) -> Result<Convergence, Error> {
+        validate_packed_len(n, a.len())?;
+
+        let diagonal = (0..n)
+            .map(|i| a[upper::base::get_row_start_index(i, n)])
+            .collect::<Vec<_>>();
+        self.solve(&diagonal, b, x, |x, y| spmv_upper(a, n, 1.0, x, 0.0, y))
+    }
+
+    /// Solve `A x = b`, where `A` is the lower packed symmetric triangle, `a`,
+    /// starting from and overwriting `x`.
+    ///
+    /// Stops with the last finite iterate, reporting no convergence, if a
+    /// search direction, `p`, has `pᵀ A p <= 0`, indicating that `A` is not
+    /// positive definite.
+    pub fn solve_lower(
+        &self,
+        a: &[f64],
+        n: usize,
+        b: &[f64],
+        x: &mut [f64],
+    ) -> Result<Convergence, Error> {
+        validate_packed_len(n, a.len())?;
+
+        let diagonal = (0..n)
+            .map(|i| a[lower::base::get_element_index(i, i)])
+            .collect::<Vec<_>>();
+        self.solve(&diagonal, b, x, |x, y| spmv_lower(a, n, 1.0, x, 0.0, y))
+    }
+
+    /// Solve `A x = b`, where `mv` calculates `y = A x` and `diagonal` is the
+    /// diagonal of `A`.
+    fn solve(
+        &self,
+        diagonal: &[f64],
+        b: &[f64],
+        x: &mut [f64],
+        mv: impl Fn(&[f64], &mut [f64]) -> Result<(), Error>,
+    ) -> Result<Convergence, Error> {
+        let n = diagonal.len();
+        validate_vec_len(n, b.len())?;
+        validate_vec_len(n, x.len())?;
+        // The diagonal of a positive definite matrix is positive.
+        for (index, el) in diagonal.iter().enumerate() {
+            if *el == 0.0 {
+                return Err(Error::Singular { index });
+            }
+            if *el < 0.0 || el.is_nan() {
+                return Err(Error::NotPositiveDefinite { index });
+            }
+        }
+
+        let precondition = |r: &[f64], z: &mut [f64]| match self.preconditioner {
+            Preconditioner::Identity => z.copy_from_slice(r),
+            Preconditioner::Jacobi => z
+                .iter_mut()
+                .zip(r.iter().zip(diagonal.iter()))
+                .for_each(|(z, (r, d))| *z = r / d),
+        };
+        let dot = |a: &[f64], b: &[f64]| a.iter().zip(b.iter()).map(|(a, b)| a * b).sum::<f64>();
+
+        let b_norm = dot(b, b).sqrt();
+        if b_norm == 0.0 {
+            x.fill(0.0);
+            return Ok(Convergence {
+                converged: true,
+                iterations: 0,
+                residual: 0.0,
+            });
+        }
+
+        let mut ap = vec![0.0; n];
+        mv(x, &mut ap)?;
+        let mut r = b
+            .iter()
+            .zip(ap.iter())
+            .map(|(b, ax)| b - ax)
+            .collect::<Vec<_>>();
+        let mut z = vec![0.0; n];
+        precondition(&r, &mut z);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+
+        let mut iterations = 0;
+        let mut residual = dot(&r, &r).sqrt() / b_norm;
+        while residual > self.tol && iterations < self.max_iter {
+            mv(&p, &mut ap)?;
+            let pap = dot(&p, &ap);
+            // Breakdown, where `A` is not positive definite along `p`.
+            if pap <= 0.0 || !pap.is_finite() {
+                break;
+            }
+
+            let alpha = rz / pap;
+            x.iter_mut()
+                .zip(p.iter())
+                .for_each(|(x, p)| *x += alpha * p);
+            r.iter_mut()
+                .zip(ap.iter())
+                .for_each(|(r, ap)| *r -= alpha * ap);
+
+            iterations += 1;
+            residual = dot(&r, &r).sqrt() / b_norm;
+
+            precondition(&r, &mut z);
+            let rz_next = dot(&r, &z);
+            let beta = rz_next / rz;
+            p.iter_mut()
+                .zip(z.iter())
+                .for_each(|(p, z)| *p = z + beta * *p);
+            rz = rz_next;
+        }
+
+        Ok(Convergence {
+            converged: residual <= self.tol,
+            iterations,
+            residual,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-8, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_cg_upper() {
+        // `A = [[4, 1, 0], [1, 3, 1], [0, 1, 2]]`.
+        #[rustfmt::skip]
+        let a = [
+            4.0, 1.0, 0.0,
+                 3.0, 1.0,
+                      2.0,
+        ];
+        let mut x = [0.0; 3];
+
+        // `A [1, 2, 3]ᵀ = [6, 10, 8]ᵀ`.
+        let convergence = ConjugateGradient::new()
+            .solve_upper(&a, 3, &[6.0, 10.0, 8.0], &mut x)
+            .unwrap();
+
+        assert!(convergence.converged);
+        assert!(convergence.iterations <= 3);
+        assert_close(&x, &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_cg_lower_jacobi() {
+        #[rustfmt::skip]
+        let a = [
+            4.0,
+            1.0, 3.0,
+            0.0, 1.0, 2.0,
+        ];
+        let mut x = [1.0; 3];
+
+        let convergence = ConjugateGradient::new()
+            .preconditioner(Preconditioner::Jacobi)
+            .solve_lower(&a, 3, &[6.0, 10.0, 8.0], &mut x)
+            .unwrap();
+
+        assert!(convergence.converged);
+        assert!(convergence.residual <= 1e-10);
+        assert_close(&x, &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_cg_syrk() {
+        // `A = Xᵀ X` for the column-major `X` of 3 rows and 2 columns.
+        let a = crate::linalg::blas::syrk_lower(&[1.0, 2.0, 0.0, 1.0, 1.0, 3.0], 2).unwrap();
+        let mut x = [0.0; 2];
+
+        // `A = [[5, 3], [3, 11]]` and `A [1, -1]ᵀ = [2, -8]ᵀ`.
+        let convergence = ConjugateGradient::new()
+            .solve_lower(&a, 2, &[2.0, -8.0], &mut x)
+            .unwrap();
+
+        assert!(convergence.converged);
+        assert_close(&x, &[1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_cg_not_converged() {
+        #[rustfmt::skip]
+        let a = [
+            4.0, 1.0, 0.0,
+                 3.0, 1.0,
+                      2.0,
+        ];
+        let mut x = [0.0; 3];
+
+        let convergence = ConjugateGradient::new()
+            .max_iter(1)
+            .solve_upper(&a, 3, &[6.0, 10.0, 8.0], &mut x)
+            .unwrap();
+
+        assert!(!convergence.converged);
+        assert_eq!(convergence.iterations, 1);
+    }
+
+    #[test]
+    fn test_cg_breakdown() {
+        // `A = [[1, 2], [2, 1]]` is indefinite, with `pᵀ A p < 0` for
+        // `p = [1, -1]ᵀ`.
+        let a = [1.0, 2.0, 1.0];
+        let mut x = [0.0; 2];
+
+        let convergence = ConjugateGradient::new()
+            .solve_upper(&a, 2, &[1.0, -1.0], &mut x)
+            .unwrap();
+
+        assert!(!convergence.converged);
+        assert_eq!(convergence.iterations, 0);
+        assert_eq!(x, [0.0; 2]);
+    }
+
+    #[test]
+    fn test_cg_invalid() {
+        #[rustfmt::skip]
+        let a = [
+            4.0, 1.0, 0.0,
+                 3.0, 1.0,
+                      2.0,
+        ];
+
+        assert_eq!(
+            ConjugateGradient::new().solve_upper(&a, 3, &[1.0; 2], &mut [0.0; 3]),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            ConjugateGradient::new().solve_upper(&a, 3, &[1.0; 3], &mut [0.0; 4]),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            ConjugateGradient::new().solve_upper(&a, 4, &[1.0; 4], &mut [0.0; 4]),
+            Err(Error::InvalidLength {
+                expected: 10,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            ConjugateGradient::new().solve_lower(&a, 2, &[1.0; 2], &mut [0.0; 2]),
+            Err(Error::InvalidLength {
+                expected: 3,
+                actual: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_cg_invalid_diagonal() {
+        let singular = [4.0, 1.0, 0.0, 0.0, 1.0, 2.0];
+        let indefinite = [4.0, 1.0, 0.0, -3.0, 1.0, 2.0];
+
+        for preconditioner in [Preconditioner::Identity, Preconditioner::Jacobi] {
+            let cg = ConjugateGradient::new().preconditioner(preconditioner);
+
+            assert_eq!(
+                cg.solve_upper(&singular, 3, &[1.0; 3], &mut [0.0; 3]),
+                Err(Error::Singular { index: 1 })
+            );
+            assert_eq!(
+                cg.solve_upper(&indefinite, 3, &[1.0; 3], &mut [0.0; 3]),
+                Err(Error::NotPositiveDefinite { index: 1 })
+            );
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Cholesky and `LDLᵀ` factorisation of packed symmetric matrices.
+//!
+//! Factorises a symmetric matrix stored as a lower packed triangle in place,
+//! overwriting it with the lower packed factor.
+use super::solve::{solve_lower, solve_lower_transpose};
+use super::{validate_cols_len, Diagonal};
+use crate::lower::base;
+use crate::ops::validate_packed_len;
+use crate::Error;
+
+/// Get the elements of row `i` of a lower packed triangle.
+fn row(a: &[f64], i: usize) -> &[f64] {
+    &a[base::get_row_start_index(i)..][..=i]
+}
+
+/// Factorise the lower packed symmetric matrix, `a`, as `L Lᵀ` in place.
+///
+/// Returns an [`Error::NotPositiveDefinite`] if `a` is not positive definite.
+pub fn cholesky(a: &mut [f64], n: usize) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+
+    for i in 0..n {
+        let start = base::get_row_start_index(i);
+        for j in 0..=i {
+            let dot = a[start..start + j]
+                .iter()
+                .zip(row(a, j)[..j].iter())
+                .map(|(a, b)| a * b)
+                .sum::<f64>();
+            let sum = a[start + j] - dot;
+
+            a[start + j] = if i == j {
+                if sum.is_nan() || sum <= 0.0 {
+                    return Err(Error::NotPositiveDefinite { index: i });
+                }
+                sum.sqrt()
+            } else {
+                sum / row(a, j)[j]
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// Solve `A x = b` in place, where `l` is the Cholesky factor of `A`.
+pub fn cholesky_solve(l: &[f64], n: usize, b: &mut [f64]) -> Result<(), Error> {
+    solve_lower(l, n, Diagonal::NonUnit, b)?;
+    solve_lower_transpose(l, n, Diagonal::NonUnit, b)
+}
+
+/// Get the natural logarithm of the determinant of `A`, where `l` is the
+/// Cholesky factor of `A`.
+pub fn cholesky_log_det(l: &[f64], n: usize) -> Result<f64, Error> {
+    validate_packed_len(n, l.len())?;
+
+    Ok(2.0 * (0..n).map(|i| row(l, i)[i].ln()).sum::<f64>())
+}
+
+/// Overwrite the Cholesky factor of `A`, `l`, with the lower packed inverse of `A`.
+pub fn cholesky_inverse(l: &mut [f64], n: usize) -> Result<(), Error> {
+    validate_packed_len(n, l.len())?;
+
+    let mut inverse = vec![0.0; l.len()];
+    let mut col = vec![0.0; n];
+    for j in 0..n {
+        col.iter_mut().for_each(|el| *el = 0.0);
+        col[j] = 1.0;
+        cholesky_solve(l, n, &mut col)?;
+
+        for (i, el) in col.iter().enumerate().skip(j) {
+            inverse[base::get_element_index(i, j)] = *el;
+        }
+    }
+
+    l.copy_from_slice(&inverse);
+    Ok(())
+}
+
+/// Factorise the lower packed symmetric matrix, `a`, as `L D Lᵀ` in place,
+/// without pivoting.
+///
+/// The unit lower triangle, `L`, is stored below the diagonal, and the diagonal
+/// matrix, `D`, on the diagonal. Returns an [`Error::Singular`] if an element
+/// of `D` is zero.
+pub fn ldl(a: &mut [f64], n: usize) -> Result<(), Error> {
+    validate_packed_len(n, a.len())?;
+
+    for i in 0..n {
+        let start = base::get_row_start_index(i);
+        for j in 0..=i {
+            let dot = (0..j)
+                .map(|k| a[start + k] * row(a, j)[k] * row(a, k)[k])
+                .sum::<f64>();
+            let sum = a[start + j] - dot;
+
+            a[start + j] = if i == j {
+                if sum == 0.0 {
+                    return Err(Error::Singular { index: i });
+                }
+                sum
+            } else {
+                sum / row(a, j)[j]
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// Solve `A x = b` in place, where `ld` is the `L D Lᵀ` factorisation of `A`.
+pub fn ldl_solve(ld: &[f64], n: usize, b: &mut [f64]) -> Result<(), Error> {
+    validate_packed_len(n, ld.len())?;
+    validate_cols_len(n, b.len())?;
+
+    solve_lower(ld, n, Diagonal::Unit, b)?;
+    for x in b.chunks_exact_mut(n) {
+        x.iter_mut()
+            .enumerate()
+            .for_each(|(i, el)| *el /= row(ld, i)[i]);
+    }
+    solve_lower_transpose(ld, n, Diagonal::Unit, b)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-10, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[rustfmt::skip]
+    const A: [f64; 6] = [
+        4.0,
+        2.0, 5.0,
+        -2.0, 1.0, 6.0,
+    ];
+
+    /// Multiply the lower packed symmetric matrix, `A`, by `x`.
+    fn mul(x: &[f64]) -> Vec<f64> {
+        let el = |i: usize, j: usize| A[base::get_element_index(i.max(j), i.min(j))];
+        (0..3)
+            .map(|i| (0..3).map(|j| el(i, j) * x[j]).sum())
+            .collect()
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let mut l = A;
+        cholesky(&mut l, 3).unwrap();
+
+        #[rustfmt::skip]
+        assert_close(&l, &[
+            2.0,
+            1.0, 2.0,
+            -1.0, 1.0, 2.0,
+        ]);
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        #[rustfmt::skip]
+        let mut a = [
+            1.0,
+            2.0, 1.0,
+        ];
+
+        assert_eq!(
+            cholesky(&mut a, 2),
+            Err(Error::NotPositiveDefinite { index: 1 })
+        );
+        assert_eq!(
+            cholesky(&mut [f64::NAN], 1),
+            Err(Error::NotPositiveDefinite { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let mut l = A;
+        cholesky(&mut l, 3).unwrap();
+
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&x);
+        cholesky_solve(&l, 3, &mut b).unwrap();
+
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_cholesky_log_det() {
+        let mut l = A;
+        cholesky(&mut l, 3).unwrap();
+
+        assert!((cholesky_log_det(&l, 3).unwrap() - 64f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_inverse() {
+        let mut inverse = A;
+        cholesky(&mut inverse, 3).unwrap();
+        cholesky_inverse(&mut inverse, 3).unwrap();
+
+        // Multiply each column of `A` by the inverse.
+        let el = |i: usize, j: usize| inverse[base::get_element_index(i.max(j), i.min(j))];
+        for j in 0..3 {
+            let col = (0..3).map(|i| el(i, j)).collect::<Vec<_>>();
+            let mut expected = [0.0; 3];
+            expected[j] = 1.0;
+
+            assert_close(&mul(&col), &expected);
+        }
+    }
+
+    #[test]
+    fn test_ldl() {
+        #[rustfmt::skip]
+        let mut a = [
+            4.0,
+            2.0, -3.0,
+            -2.0, 1.0, 6.0,
+        ];
+        ldl(&mut a, 3).unwrap();
+
+        // `D = diag(4, -4, 6)` with `L` below the diagonal.
+        #[rustfmt::skip]
+        assert_close(&a, &[
+            4.0,
+            0.5, -4.0,
+            -0.5, -0.5, 6.0,
+        ]);
+    }
+
+    #[test]
+    fn test_ldl_solve() {
+        let mut ld = A;
+        ldl(&mut ld, 3).unwrap();
+
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&x);
+        ldl_solve(&ld, 3, &mut b).unwrap();
+
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_ldl_singular() {
+        #[rustfmt::skip]
+        let mut a = [
+            1.0,
+            1.0, 1.0,
+        ];
+
+        assert_eq!(ldl(&mut a, 2), Err(Error::Singular { index: 1 }));
+    }
+}
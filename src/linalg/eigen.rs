@@ -0,0 +1,300 @@
+//! Eigen-decomposition of packed symmetric matrices.
+use super::blas::{spmv_lower, spmv_upper};
+use crate::ops::validate_packed_len;
+use crate::{lower, upper, Error};
+
+/// The maximum number of Jacobi sweeps before failing to converge.
+const MAX_SWEEPS: usize = 64;
+
+/// The eigenvalues, and optionally eigenvectors, of a symmetric matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigen {
+    /// The eigenvalues in descending order.
+    pub values: Vec<f64>,
+    /// The unit eigenvectors of each eigenvalue, where the eigenvector of
+    /// eigenvalue `k` is `vectors[k * n..(k + 1) * n]`.
+    pub vectors: Option<Vec<f64>>,
+}
+
+/// Get the eigen-decomposition of an upper packed symmetric triangle, `a`, by
+/// the cyclic Jacobi method, calculating eigenvectors if `vectors` is `true`.
+pub fn eigen_upper(a: &[f64], n: usize, vectors: bool) -> Result<Eigen, Error> {
+    validate_packed_len(n, a.len())?;
+
+    let lower = lower::base::iter_triangle_indices(n)
+        .map(|(i, j)| a[upper::base::get_element_index(j, i - j, n)])
+        .collect();
+    jacobi(lower, n, vectors)
+}
+
+/// Get the eigen-decomposition of a lower packed symmetric triangle, `a`, by
+/// the cyclic Jacobi method, calculating eigenvectors if `vectors` is `true`.
+pub fn eigen_lower(a: &[f64], n: usize, vectors: bool) -> Result<Eigen, Error> {
+    validate_packed_len(n, a.len())?;
+
+    jacobi(a.to_vec(), n, vectors)
+}
+
+/// Diagonalise the lower packed symmetric triangle, `a`, by Jacobi rotations.
+fn jacobi(mut a: Vec<f64>, n: usize, vectors: bool) -> Result<Eigen, Error> {
+    let idx = |i: usize, j: usize| lower::base::get_element_index(i.max(j), i.min(j));
+
+    // Row-major accumulated rotations.
+    let mut v = vectors.then(|| {
+        let mut v = vec![0.0; n * n];
+        (0..n).for_each(|k| v[k * n + k] = 1.0);
+        v
+    });
+
+    let total = (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| a[idx(i, j)].powi(2))
+        .sum::<f64>();
+    let off_diagonal = |a: &[f64]| {
+        lower::base::iter_triangle_indices(n)
+            .filter(|(i, j)| i != j)
+            .map(|(i, j)| 2.0 * a[lower::base::get_element_index(i, j)].powi(2))
+            .sum::<f64>()
+    };
+
+    let mut sweeps = 0;
+    while off_diagonal(&a) > f64::EPSILON.powi(2) * total {
+        if sweeps == MAX_SWEEPS {
+            return Err(Error::NotConverged { iterations: sweeps });
+        }
+        sweeps += 1;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[idx(p, q)];
+                if apq == 0.0 {
+                    continue;
+                }
+
+                let (app, aqq) = (a[idx(p, p)], a[idx(q, q)]);
+                let theta = (aqq - app) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+
+                for k in (0..n).filter(|k| *k != p && *k != q) {
+                    let (akp, akq) = (a[idx(k, p)], a[idx(k, q)]);
+                    a[idx(k, p)] = c * akp - s * akq;
+                    a[idx(k, q)] = s * akp + c * akq;
+                }
+                a[idx(p, p)] = app - t * apq;
+                a[idx(q, q)] = aqq + t * apq;
+                a[idx(p, q)] = 0.0;
+
+                if let Some(v) = v.as_mut() {
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * vkp - s * vkq;
+                        v[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|x, y| a[idx(*y, *y)].total_cmp(&a[idx(*x, *x)]));
+
+    Ok(Eigen {
+        values: order.iter().map(|k| a[idx(*k, *k)]).collect(),
+        vectors: v.map(|v| {
+            order
+                .iter()
+                .flat_map(|k| (0..n).map(|i| v[i * n + k]).collect::<Vec<_>>())
+                .collect()
+        }),
+    })
+}
+
+/// Get the eigenvalue of largest magnitude, and its unit eigenvector, of an
+/// upper packed symmetric triangle, `a`, by power iteration.
+///
+/// Returns an [`Error::NotConverged`] if the eigenvector does not converge to
+/// within `tol` in `max_iter` iterations.
+pub fn dominant_eigenpair_upper(
+    a: &[f64],
+    n: usize,
+    max_iter: usize,
+    tol: f64,
+) -> Result<(f64, Vec<f64>), Error> {
+    validate_packed_len(n, a.len())?;
+
+    power_iteration(n, max_iter, tol, |x, y| spmv_upper(a, n, 1.0, x, 0.0, y))
+}
+
+/// Get the eigenvalue of largest magnitude, and its unit eigenvector, of a
+/// lower packed symmetric triangle, `a`, by power iteration.
+///
+/// Returns an [`Error::NotConverged`] if the eigenvector does not converge to
+/// within `tol` in `max_iter` iterations.
+pub fn dominant_eigenpair_lower(
+    a: &[f64],
+    n: usize,
+    max_iter: usize,
+    tol: f64,
+) -> Result<(f64, Vec<f64>), Error> {
+    validate_packed_len(n, a.len())?;
+
+    power_iteration(n, max_iter, tol, |x, y| spmv_lower(a, n, 1.0, x, 0.0, y))
+}
+
+/// Find the dominant eigenpair of the operator, `mv`, calculating `y = A x`.
+fn power_iteration(
+    n: usize,
+    max_iter: usize,
+    tol: f64,
+    mut mv: impl FnMut(&[f64], &mut [f64]) -> Result<(), Error>,
+) -> Result<(f64, Vec<f64>), Error> {
+    let norm = |x: &[f64]| x.iter().map(|el| el * el).sum::<f64>().sqrt();
+
+    // A start vector unlikely to be orthogonal to the dominant eigenvector.
+    let mut x = (0..n)
+        .map(|i| 1.0 + i as f64 / n as f64)
+        .collect::<Vec<_>>();
+    let x_norm = norm(&x);
+    x.iter_mut().for_each(|el| *el /= x_norm);
+
+    let mut y = vec![0.0; n];
+    for _ in 0..max_iter {
+        mv(&x, &mut y)?;
+
+        let value = x.iter().zip(y.iter()).map(|(x, y)| x * y).sum::<f64>();
+        let y_norm = norm(&y);
+        if y_norm == 0.0 {
+            return Ok((0.0, x));
+        }
+
+        let sign = if value < 0.0 { -1.0 } else { 1.0 };
+        y.iter_mut().for_each(|el| *el *= sign / y_norm);
+        let diff = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| (x - y).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        std::mem::swap(&mut x, &mut y);
+        if diff < tol {
+            return Ok((value, x));
+        }
+    }
+
+    Err(Error::NotConverged {
+        iterations: max_iter,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64], tol: f64) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < tol, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[rustfmt::skip]
+    const UPPER: [f64; 6] = [
+        2.0, 1.0, 0.0,
+             2.0, 1.0,
+                  2.0,
+    ];
+
+    #[rustfmt::skip]
+    const LOWER: [f64; 6] = [
+        2.0,
+        1.0, 2.0,
+        0.0, 1.0, 2.0,
+    ];
+
+    #[test]
+    fn test_eigen_values() {
+        let sqrt2 = 2f64.sqrt();
+        let expected = [2.0 + sqrt2, 2.0, 2.0 - sqrt2];
+
+        assert_close(
+            &eigen_upper(&UPPER, 3, false).unwrap().values,
+            &expected,
+            1e-12,
+        );
+        assert_close(
+            &eigen_lower(&LOWER, 3, false).unwrap().values,
+            &expected,
+            1e-12,
+        );
+        assert_eq!(eigen_lower(&LOWER, 3, false).unwrap().vectors, None);
+    }
+
+    #[test]
+    fn test_eigen_vectors() {
+        let n = 3;
+        let eigen = eigen_lower(&LOWER, n, true).unwrap();
+        let vectors = eigen.vectors.unwrap();
+
+        for (k, value) in eigen.values.iter().enumerate() {
+            let v = &vectors[k * n..(k + 1) * n];
+            let mut av = vec![0.0; n];
+            spmv_lower(&LOWER, n, 1.0, v, 0.0, &mut av).unwrap();
+
+            let expected = v.iter().map(|el| el * value).collect::<Vec<_>>();
+            assert_close(&av, &expected, 1e-12);
+            assert!((v.iter().map(|el| el * el).sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_eigen_diagonal() {
+        #[rustfmt::skip]
+        let a = [
+            1.0,
+            0.0, 3.0,
+        ];
+        let eigen = eigen_lower(&a, 2, true).unwrap();
+
+        assert_eq!(eigen.values, [3.0, 1.0]);
+        assert_eq!(eigen.vectors.unwrap(), [0.0, 1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_dominant_eigenpair() {
+        let sqrt2 = 2f64.sqrt();
+        let expected = [0.5, sqrt2 / 2.0, 0.5];
+
+        let (value, vector) = dominant_eigenpair_upper(&UPPER, 3, 1000, 1e-12).unwrap();
+        assert!((value - (2.0 + sqrt2)).abs() < 1e-10);
+        assert_close(&vector, &expected, 1e-10);
+
+        let (value, vector) = dominant_eigenpair_lower(&LOWER, 3, 1000, 1e-12).unwrap();
+        assert!((value - (2.0 + sqrt2)).abs() < 1e-10);
+        assert_close(&vector, &expected, 1e-10);
+    }
+
+    #[test]
+    fn test_dominant_eigenpair_negative() {
+        #[rustfmt::skip]
+        let a = [
+            -4.0,
+             1.0, 1.0,
+        ];
+        let (value, _) = dominant_eigenpair_lower(&a, 2, 1000, 1e-12).unwrap();
+        let expected = -1.5 - (25f64 / 4.0 + 1.0).sqrt();
+
+        assert!((value - expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_dominant_eigenpair_not_converged() {
+        assert_eq!(
+            dominant_eigenpair_lower(&LOWER, 3, 2, 1e-12),
+            Err(Error::NotConverged { iterations: 2 })
+        );
+    }
+}
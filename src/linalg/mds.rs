@@ -0,0 +1,131 @@
+//! Classical multidimensional scaling of distance matrices.
+use super::eigen::eigen_lower;
+use crate::ops::tri_num;
+use crate::{lower, Error};
+
+/// A classical multidimensional scaling embedding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mds {
+    /// The number of dimensions, `k`, of the embedding.
+    pub k: usize,
+    /// The row-major `n × k` coordinates, where the coordinates of point `i`
+    /// are `embedding[i * k..(i + 1) * k]`.
+    pub embedding: Vec<f64>,
+    /// The eigenvalue of each dimension of the embedding, in descending order.
+    pub eigenvalues: Vec<f64>,
+    /// The proportion of the sum of positive eigenvalues explained by each
+    /// dimension of the embedding.
+    pub explained_variance: Vec<f64>,
+}
+
+/// Embed `n` points in `k` dimensions from the pairwise distances, `(i, j, d)`,
+/// of each `i != j` pair, given once per pair.
+///
+/// Dimensions with non-positive eigenvalues have zero coordinates.
+pub(crate) fn classical_mds(
+    n: usize,
+    distances: impl Iterator<Item = (usize, usize, f64)>,
+    k: usize,
+) -> Result<Mds, Error> {
+    if k > n {
+        return Err(Error::DimensionMismatch {
+            expected: n,
+            actual: k,
+        });
+    }
+
+    // Squared distances, with row and grand means for double-centring.
+    let mut b = vec![0.0; tri_num(n)];
+    let mut row_means = vec![0.0; n];
+    for (i, j, d) in distances {
+        let d2 = d * d;
+        b[lower::base::get_element_index(i.max(j), i.min(j))] = d2;
+        row_means[i] += d2 / n as f64;
+        row_means[j] += d2 / n as f64;
+    }
+    let grand_mean = row_means.iter().sum::<f64>() / n as f64;
+
+    for (i, j) in lower::base::iter_triangle_indices(n) {
+        let el = &mut b[lower::base::get_element_index(i, j)];
+        *el = -0.5 * (*el - row_means[i] - row_means[j] + grand_mean);
+    }
+
+    let eigen = eigen_lower(&b, n, true)?;
+    let vectors = eigen.vectors.unwrap_or_default();
+    let total = eigen.values.iter().filter(|el| **el > 0.0).sum::<f64>();
+
+    let mut embedding = vec![0.0; n * k];
+    for (c, value) in eigen.values.iter().take(k).enumerate() {
+        let scale = value.max(0.0).sqrt();
+        for i in 0..n {
+            embedding[i * k + c] = vectors[c * n + i] * scale;
+        }
+    }
+
+    let eigenvalues = eigen.values[..k].to_vec();
+    let explained_variance = eigenvalues
+        .iter()
+        .map(|el| {
+            if total > 0.0 {
+                el.max(0.0) / total
+            } else {
+                0.0
+            }
+        })
+        .collect();
+
+    Ok(Mds {
+        k,
+        embedding,
+        eigenvalues,
+        explained_variance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Get the distance between points `i` and `j` of the row-major embedding.
+    fn distance(mds: &Mds, i: usize, j: usize) -> f64 {
+        let (k, x) = (mds.k, &mds.embedding);
+        (0..k)
+            .map(|c| (x[i * k + c] - x[j * k + c]).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    #[test]
+    fn test_classical_mds() {
+        // The corners of a 3 × 4 rectangle.
+        let points = [(0.0, 0.0), (3.0, 0.0), (3.0, 4.0), (0.0, 4.0)];
+        let d = |i: usize, j: usize| {
+            let ((xi, yi), (xj, yj)): ((f64, f64), (f64, f64)) = (points[i], points[j]);
+            (xi - xj).hypot(yi - yj)
+        };
+        let pairs = (0..4).flat_map(|i| (i + 1..4).map(move |j| (i, j, d(i, j))));
+
+        let mds = classical_mds(4, pairs, 2).unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((distance(&mds, i, j) - d(i, j)).abs() < 1e-10);
+            }
+        }
+        assert!((mds.eigenvalues[0] - 16.0).abs() < 1e-10);
+        assert!((mds.eigenvalues[1] - 9.0).abs() < 1e-10);
+        assert!((mds.explained_variance[0] - 16.0 / 25.0).abs() < 1e-10);
+        assert!((mds.explained_variance[1] - 9.0 / 25.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_classical_mds_invalid_k() {
+        assert_eq!(
+            classical_mds(2, [(0, 1, 1.0)].into_iter(), 3),
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,58 @@
+//! Linear algebra operations on packed triangle matrices.
+//!
+//! Operates on `f64` collections of `tri_num(n)` elements, including the
+//! diagonal, indexed by [`upper::base`](crate::upper::base) for upper packed
+//! triangles and [`lower::base`](crate::lower::base) for lower packed triangles.
+pub mod blas;
+pub mod cg;
+pub mod cholesky;
+pub mod eigen;
+pub mod mds;
+pub mod norm;
+pub mod qr;
+pub mod solve;
+pub mod triangular;
+
+use crate::Error;
+
+/// The diagonal of a triangular matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagonal {
+    /// Diagonal elements are read from the collection.
+    NonUnit,
+    /// Diagonal elements are assumed to be `1.0`, and are not read.
+    Unit,
+}
+
+/// The structure of the full matrix represented by a packed triangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Structure {
+    /// Elements outside of the triangle are zero.
+    Triangular,
+    /// Elements outside of the triangle mirror the triangle.
+    Symmetric,
+}
+
+/// Validate the length of an operand of one or more columns of length `n`.
+fn validate_cols_len(n: usize, len: usize) -> Result<(), Error> {
+    if len % n != 0 {
+        return Err(Error::DimensionMismatch {
+            expected: n,
+            actual: len,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate the length of a vector operand of length `n`.
+fn validate_vec_len(n: usize, len: usize) -> Result<(), Error> {
+    if len != n {
+        return Err(Error::DimensionMismatch {
+            expected: n,
+            actual: len,
+        });
+    }
+
+    Ok(())
+}
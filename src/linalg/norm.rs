@@ -0,0 +1,270 @@
+//! Norms, traces and quadratic forms of packed triangle matrices.
+//!
+//! Each operation traverses the packed triangle once. For a
+//! [`Structure::Symmetric`] matrix, off-diagonal elements are applied to both
+//! of their mirrored positions.
+use super::{validate_vec_len, Structure};
+use crate::ops::validate_packed_len;
+use crate::{lower, upper, Error};
+
+/// Iterate the `(i, j)` indices and elements of an upper packed triangle.
+fn iter_upper(
+    a: &[f64],
+    n: usize,
+) -> Result<impl Iterator<Item = (usize, usize, f64)> + '_, Error> {
+    validate_packed_len(n, a.len())?;
+
+    Ok(upper::base::iter_triangle_indices(n)
+        .zip(a.iter())
+        .map(|((i, j), el)| (i, j, *el)))
+}
+
+/// Iterate the `(i, j)` indices and elements of a lower packed triangle.
+fn iter_lower(
+    a: &[f64],
+    n: usize,
+) -> Result<impl Iterator<Item = (usize, usize, f64)> + '_, Error> {
+    validate_packed_len(n, a.len())?;
+
+    Ok(lower::base::iter_triangle_indices(n)
+        .zip(a.iter())
+        .map(|((i, j), el)| (i, j, *el)))
+}
+
+/// Calculate the Frobenius norm of the elements, `(i, j, el)`.
+fn frobenius_norm(iter: impl Iterator<Item = (usize, usize, f64)>, structure: Structure) -> f64 {
+    iter.map(|(i, j, el)| match structure {
+        Structure::Symmetric if i != j => 2.0 * el * el,
+        _ => el * el,
+    })
+    .sum::<f64>()
+    .sqrt()
+}
+
+/// Calculate the largest absolute row sum of the elements, `(i, j, el)`.
+fn inf_norm(
+    iter: impl Iterator<Item = (usize, usize, f64)>,
+    n: usize,
+    structure: Structure,
+) -> f64 {
+    let mut sums = vec![0.0; n];
+    for (i, j, el) in iter {
+        sums[i] += el.abs();
+        if structure == Structure::Symmetric && i != j {
+            sums[j] += el.abs();
+        }
+    }
+
+    sums.into_iter().fold(0.0, f64::max)
+}
+
+/// Calculate `xᵀ A x` from the elements, `(i, j, el)`.
+fn quadratic_form(
+    iter: impl Iterator<Item = (usize, usize, f64)>,
+    x: &[f64],
+    structure: Structure,
+) -> f64 {
+    iter.map(|(i, j, el)| match structure {
+        Structure::Symmetric if i != j => 2.0 * el * x[i] * x[j],
+        _ => el * x[i] * x[j],
+    })
+    .sum()
+}
+
+/// Calculate the Frobenius norm of an upper packed triangle, `a`.
+pub fn frobenius_norm_upper(a: &[f64], n: usize, structure: Structure) -> Result<f64, Error> {
+    Ok(frobenius_norm(iter_upper(a, n)?, structure))
+}
+
+/// Calculate the Frobenius norm of a lower packed triangle, `a`.
+pub fn frobenius_norm_lower(a: &[f64], n: usize, structure: Structure) -> Result<f64, Error> {
+    Ok(frobenius_norm(iter_lower(a, n)?, structure))
+}
+
+/// Calculate the 1-norm, the largest absolute column sum, of an upper packed
+/// triangle, `a`.
+pub fn one_norm_upper(a: &[f64], n: usize, structure: Structure) -> Result<f64, Error> {
+    Ok(inf_norm(
+        iter_upper(a, n)?.map(|(i, j, el)| (j, i, el)),
+        n,
+        structure,
+    ))
+}
+
+/// Calculate the 1-norm, the largest absolute column sum, of a lower packed
+/// triangle, `a`.
+pub fn one_norm_lower(a: &[f64], n: usize, structure: Structure) -> Result<f64, Error> {
+    Ok(inf_norm(
+        iter_lower(a, n)?.map(|(i, j, el)| (j, i, el)),
+        n,
+        structure,
+    ))
+}
+
+/// Calculate the infinity norm, the largest absolute row sum, of an upper
+/// packed triangle, `a`.
+pub fn inf_norm_upper(a: &[f64], n: usize, structure: Structure) -> Result<f64, Error> {
+    Ok(inf_norm(iter_upper(a, n)?, n, structure))
+}
+
+/// Calculate the infinity norm, the largest absolute row sum, of a lower packed
+/// triangle, `a`.
+pub fn inf_norm_lower(a: &[f64], n: usize, structure: Structure) -> Result<f64, Error> {
+    Ok(inf_norm(iter_lower(a, n)?, n, structure))
+}
+
+/// Get the largest absolute element of a packed triangle, `a`, of either layout
+/// or structure.
+pub fn max_abs(a: &[f64], n: usize) -> Result<f64, Error> {
+    validate_packed_len(n, a.len())?;
+
+    Ok(a.iter().fold(0.0, |max, el| max.max(el.abs())))
+}
+
+/// Calculate the trace, the sum of the diagonal, of an upper packed triangle, `a`.
+pub fn trace_upper(a: &[f64], n: usize) -> Result<f64, Error> {
+    validate_packed_len(n, a.len())?;
+
+    Ok((0..n)
+        .map(|i| a[upper::base::get_row_start_index(i, n)])
+        .sum())
+}
+
+/// Calculate the trace, the sum of the diagonal, of a lower packed triangle, `a`.
+pub fn trace_lower(a: &[f64], n: usize) -> Result<f64, Error> {
+    validate_packed_len(n, a.len())?;
+
+    Ok((0..n)
+        .map(|i| a[lower::base::get_element_index(i, i)])
+        .sum())
+}
+
+/// Calculate the quadratic form `xᵀ A x`, where `A` is an upper packed triangle.
+pub fn quadratic_form_upper(
+    a: &[f64],
+    n: usize,
+    x: &[f64],
+    structure: Structure,
+) -> Result<f64, Error> {
+    validate_vec_len(n, x.len())?;
+
+    Ok(quadratic_form(iter_upper(a, n)?, x, structure))
+}
+
+/// Calculate the quadratic form `xᵀ A x`, where `A` is a lower packed triangle.
+pub fn quadratic_form_lower(
+    a: &[f64],
+    n: usize,
+    x: &[f64],
+    structure: Structure,
+) -> Result<f64, Error> {
+    validate_vec_len(n, x.len())?;
+
+    Ok(quadratic_form(iter_lower(a, n)?, x, structure))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    // The upper and lower triangles of `[[1, -2, 3], [-2, 4, -5], [3, -5, 6]]`.
+    #[rustfmt::skip]
+    const UPPER: [f64; 6] = [
+        1.0, -2.0,  3.0,
+              4.0, -5.0,
+                    6.0,
+    ];
+
+    #[rustfmt::skip]
+    const LOWER: [f64; 6] = [
+         1.0,
+        -2.0,  4.0,
+         3.0, -5.0, 6.0,
+    ];
+
+    #[test]
+    fn test_frobenius_norm() {
+        let symmetric: f64 = 1.0 + 16.0 + 36.0 + 2.0 * (4.0 + 9.0 + 25.0);
+        let triangular: f64 = 1.0 + 16.0 + 36.0 + 4.0 + 9.0 + 25.0;
+
+        assert_eq!(
+            frobenius_norm_upper(&UPPER, 3, Structure::Symmetric),
+            Ok(symmetric.sqrt())
+        );
+        assert_eq!(
+            frobenius_norm_lower(&LOWER, 3, Structure::Symmetric),
+            Ok(symmetric.sqrt())
+        );
+        assert_eq!(
+            frobenius_norm_upper(&UPPER, 3, Structure::Triangular),
+            Ok(triangular.sqrt())
+        );
+        assert_eq!(
+            frobenius_norm_lower(&LOWER, 3, Structure::Triangular),
+            Ok(triangular.sqrt())
+        );
+    }
+
+    #[test]
+    fn test_one_inf_norm() {
+        // Symmetric absolute row and column sums are `6`, `11` and `14`.
+        let structure = Structure::Symmetric;
+        assert_eq!(one_norm_upper(&UPPER, 3, structure), Ok(14.0));
+        assert_eq!(one_norm_lower(&LOWER, 3, structure), Ok(14.0));
+        assert_eq!(inf_norm_upper(&UPPER, 3, structure), Ok(14.0));
+        assert_eq!(inf_norm_lower(&LOWER, 3, structure), Ok(14.0));
+
+        // Upper row sums are `6`, `9` and `6`, and column sums are `1`, `6` and `14`.
+        assert_eq!(one_norm_upper(&UPPER, 3, Structure::Triangular), Ok(14.0));
+        assert_eq!(inf_norm_upper(&UPPER, 3, Structure::Triangular), Ok(9.0));
+
+        // Lower row sums are `1`, `6` and `14`, and column sums are `6`, `9` and `6`.
+        assert_eq!(one_norm_lower(&LOWER, 3, Structure::Triangular), Ok(9.0));
+        assert_eq!(inf_norm_lower(&LOWER, 3, Structure::Triangular), Ok(14.0));
+    }
+
+    #[test]
+    fn test_max_abs() {
+        assert_eq!(max_abs(&UPPER, 3), Ok(6.0));
+        assert_eq!(max_abs(&[-7.0, 1.0, 2.0], 2), Ok(7.0));
+    }
+
+    #[test]
+    fn test_trace() {
+        assert_eq!(trace_upper(&UPPER, 3), Ok(11.0));
+        assert_eq!(trace_lower(&LOWER, 3), Ok(11.0));
+    }
+
+    #[test]
+    fn test_quadratic_form() {
+        let x = [1.0, 2.0, 3.0];
+
+        // `A x = [6, -9, 11]`, and `xᵀ A x = 6 - 18 + 33`.
+        assert_eq!(
+            quadratic_form_upper(&UPPER, 3, &x, Structure::Symmetric),
+            Ok(21.0)
+        );
+        assert_eq!(
+            quadratic_form_lower(&LOWER, 3, &x, Structure::Symmetric),
+            Ok(21.0)
+        );
+
+        // `U x = [6, -7, 18]`, and `xᵀ U x = 6 - 14 + 54`, where `L = Uᵀ`.
+        assert_eq!(
+            quadratic_form_upper(&UPPER, 3, &x, Structure::Triangular),
+            Ok(46.0)
+        );
+        assert_eq!(
+            quadratic_form_lower(&LOWER, 3, &x, Structure::Triangular),
+            Ok(46.0)
+        );
+        assert_eq!(
+            quadratic_form_upper(&UPPER, 3, &x[..2], Structure::Symmetric),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Householder QR decomposition with a packed upper triangular factor.
+//!
+//! Decomposes a dense `m × n` matrix, where `m >= n`, stored in consecutive
+//! columns of length `m`.
+use super::solve::solve_upper;
+use super::{validate_cols_len, Diagonal};
+use crate::ops::tri_num;
+use crate::{upper, Error};
+
+/// A QR decomposition, `A = Q R`, with `Q` stored as Householder reflectors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr {
+    /// The number of rows, `m`, of the decomposed matrix.
+    pub m: usize,
+    /// The number of columns, `n`, of the decomposed matrix.
+    pub n: usize,
+    /// The upper packed `n × n` triangular factor.
+    pub r: Vec<f64>,
+    /// The unit Householder vectors, `v`, of each reflector `I - 2 v vᵀ`, where
+    /// reflector `k` is `reflectors[k * m..(k + 1) * m]`, and is zero above row `k`.
+    pub reflectors: Vec<f64>,
+}
+
+/// Decompose the dense matrix, `a`, of `m` rows as `Q R`.
+///
+/// Returns an [`Error::DimensionMismatch`] if `a` has more columns than rows.
+pub fn qr(a: &[f64], m: usize) -> Result<Qr, Error> {
+    if m == 0 {
+        return Err(Error::EmptyAxis);
+    }
+    validate_cols_len(m, a.len())?;
+
+    let n = a.len() / m;
+    if n > m {
+        return Err(Error::DimensionMismatch {
+            expected: m,
+            actual: n,
+        });
+    }
+
+    let mut a = a.to_vec();
+    let mut r = vec![0.0; tri_num(n)];
+    let mut reflectors = vec![0.0; m * n];
+    for k in 0..n {
+        let x = &a[k * m + k..(k + 1) * m];
+        let norm = x.iter().map(|el| el * el).sum::<f64>().sqrt();
+        let alpha = if x[0] > 0.0 { -norm } else { norm };
+
+        let v = &mut reflectors[k * m + k..(k + 1) * m];
+        v.copy_from_slice(x);
+        v[0] -= alpha;
+        let v_norm = v.iter().map(|el| el * el).sum::<f64>().sqrt();
+        if v_norm > 0.0 {
+            v.iter_mut().for_each(|el| *el /= v_norm);
+        }
+
+        r[upper::base::get_element_index(k, 0, n)] = alpha;
+        for j in k + 1..n {
+            let col = &mut a[j * m + k..(j + 1) * m];
+            reflect(v, col);
+            r[upper::base::get_element_index(k, j - k, n)] = col[0];
+        }
+    }
+
+    Ok(Qr {
+        m,
+        n,
+        r,
+        reflectors,
+    })
+}
+
+/// Apply the reflector `I - 2 v vᵀ` to `x` in place.
+fn reflect(v: &[f64], x: &mut [f64]) {
+    let dot = 2.0 * v.iter().zip(x.iter()).map(|(v, x)| v * x).sum::<f64>();
+    v.iter().zip(x.iter_mut()).for_each(|(v, x)| *x -= dot * v);
+}
+
+impl Qr {
+    /// Calculate `Qᵀ b` in place, where `b` is one or more columns of length `m`.
+    pub fn apply_qt(&self, b: &mut [f64]) -> Result<(), Error> {
+        validate_cols_len(self.m, b.len())?;
+
+        let m = self.m;
+        for x in b.chunks_exact_mut(m) {
+            for k in 0..self.n {
+                reflect(&self.reflectors[k * m + k..(k + 1) * m], &mut x[k..]);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Solve the least squares problem `min ‖A x - b‖`, where `b` is one or
+    /// more columns of length `m`, returning columns of length `n`.
+    ///
+    /// Returns an [`Error::Singular`] if `A` is rank deficient.
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, Error> {
+        let mut qtb = b.to_vec();
+        self.apply_qt(&mut qtb)?;
+
+        let mut x = qtb
+            .chunks_exact(self.m)
+            .flat_map(|col| col[..self.n].iter().copied())
+            .collect::<Vec<_>>();
+        solve_upper(&self.r, self.n, Diagonal::NonUnit, &mut x)?;
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    // The 4 × 2 design matrix of a line fit at `x = 0, 1, 2, 3`.
+    const A: [f64; 8] = [1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 2.0, 3.0];
+
+    #[test]
+    fn test_qr_r() {
+        let qr = qr(&A, 4).unwrap();
+
+        // `Rᵀ R = Aᵀ A`, where `Aᵀ A = [[4, 6], [6, 14]]`.
+        let (r00, r01, r11) = (qr.r[0], qr.r[1], qr.r[2]);
+        assert_close(
+            &[r00 * r00, r00 * r01, r01 * r01 + r11 * r11],
+            &[4.0, 6.0, 14.0],
+        );
+    }
+
+    #[test]
+    fn test_qr_apply_qt() {
+        let qr = qr(&A, 4).unwrap();
+
+        // `Qᵀ A` is `R` above zeros.
+        let mut qta = A;
+        qr.apply_qt(&mut qta).unwrap();
+        assert_close(&qta, &[qr.r[0], 0.0, 0.0, 0.0, qr.r[1], qr.r[2], 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_qr_solve() {
+        let qr = qr(&A, 4).unwrap();
+
+        // An exact fit of `y = 1 + 2x`, and a least squares fit of `y = 0.8 + 0.8x`.
+        let b = [1.0, 3.0, 5.0, 7.0, 1.0, 1.0, 3.0, 3.0];
+        assert_close(&qr.solve(&b).unwrap(), &[1.0, 2.0, 0.8, 0.8]);
+    }
+
+    #[test]
+    fn test_qr_invalid() {
+        assert_eq!(
+            qr(&A, 2),
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            qr(&A, 3),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 8
+            })
+        );
+
+        // A rank deficient matrix with a zero column.
+        let qr = qr(&[1.0, 0.0, 0.0, 0.0], 2).unwrap();
+        assert_eq!(qr.solve(&[1.0, 2.0]), Err(Error::Singular { index: 1 }));
+    }
+}
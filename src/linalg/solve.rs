@@ -0,0 +1,336 @@
+//! Triangular solves of packed triangle matrices.
+//!
+//! Solves `A x = b` or `Aᵀ x = b` in place for one or more right hand sides.
+//! The right hand sides, `b`, are stored in consecutive columns of length `n`.
+use super::{validate_cols_len, Diagonal};
+use crate::ops::validate_packed_len;
+use crate::{lower, upper, Error};
+
+/// Get the diagonal element, or `1.0` for a unit diagonal.
+fn diagonal(el: f64, diag: Diagonal) -> f64 {
+    match diag {
+        Diagonal::Unit => 1.0,
+        Diagonal::NonUnit => el,
+    }
+}
+
+/// Validate the diagonal elements of a non-unit triangle, returning an
+/// [`Error::Singular`] for the first zero element before any right hand side
+/// is modified.
+fn validate_diagonal(
+    mut diagonals: impl Iterator<Item = f64>,
+    diag: Diagonal,
+) -> Result<(), Error> {
+    match diag {
+        Diagonal::Unit => Ok(()),
+        Diagonal::NonUnit => match diagonals.position(|el| el == 0.0) {
+            Some(index) => Err(Error::Singular { index }),
+            None => Ok(()),
+        },
+    }
+}
+
+/// Solve `L x = b` by forward substitution, where `L` is a lower packed triangle.
+pub fn solve_lower(l: &[f64], n: usize, diag: Diagonal, b: &mut [f64]) -> Result<(), Error> {
+    validate_packed_len(n, l.len())?;
+    validate_cols_len(n, b.len())?;
+    validate_diagonal(
+        (0..n).map(|i| l[lower::base::get_element_index(i, i)]),
+        diag,
+    )?;
+
+    for x in b.chunks_exact_mut(n) {
+        for i in 0..n {
+            let row = &l[lower::base::get_row_start_index(i)..][..=i];
+            let sum = row[..i]
+                .iter()
+                .zip(x[..i].iter())
+                .map(|(a, x)| a * x)
+                .sum::<f64>();
+
+            x[i] = (x[i] - sum) / diagonal(row[i], diag);
+        }
+    }
+
+    Ok(())
+}
+
+/// Solve `Lᵀ x = b` by back substitution, where `L` is a lower packed triangle.
+pub fn solve_lower_transpose(
+    l: &[f64],
+    n: usize,
+    diag: Diagonal,
+    b: &mut [f64],
+) -> Result<(), Error> {
+    validate_packed_len(n, l.len())?;
+    validate_cols_len(n, b.len())?;
+    validate_diagonal(
+        (0..n).map(|i| l[lower::base::get_element_index(i, i)]),
+        diag,
+    )?;
+
+    for x in b.chunks_exact_mut(n) {
+        for i in (0..n).rev() {
+            let row = &l[lower::base::get_row_start_index(i)..][..=i];
+            x[i] /= diagonal(row[i], diag);
+
+            let xi = x[i];
+            row[..i]
+                .iter()
+                .zip(x[..i].iter_mut())
+                .for_each(|(a, x)| *x -= a * xi);
+        }
+    }
+
+    Ok(())
+}
+
+/// Solve `U x = b` by back substitution, where `U` is an upper packed triangle.
+pub fn solve_upper(u: &[f64], n: usize, diag: Diagonal, b: &mut [f64]) -> Result<(), Error> {
+    validate_packed_len(n, u.len())?;
+    validate_cols_len(n, b.len())?;
+    validate_diagonal(
+        (0..n).map(|i| u[upper::base::get_row_start_index(i, n)]),
+        diag,
+    )?;
+
+    for x in b.chunks_exact_mut(n) {
+        for i in (0..n).rev() {
+            let row = &u[upper::base::get_row_start_index(i, n)..][..n - i];
+            let sum = row[1..]
+                .iter()
+                .zip(x[i + 1..].iter())
+                .map(|(a, x)| a * x)
+                .sum::<f64>();
+
+            x[i] = (x[i] - sum) / diagonal(row[0], diag);
+        }
+    }
+
+    Ok(())
+}
+
+/// Solve `Uᵀ x = b` by forward substitution, where `U` is an upper packed triangle.
+pub fn solve_upper_transpose(
+    u: &[f64],
+    n: usize,
+    diag: Diagonal,
+    b: &mut [f64],
+) -> Result<(), Error> {
+    validate_packed_len(n, u.len())?;
+    validate_cols_len(n, b.len())?;
+    validate_diagonal(
+        (0..n).map(|i| u[upper::base::get_row_start_index(i, n)]),
+        diag,
+    )?;
+
+    for x in b.chunks_exact_mut(n) {
+        for i in 0..n {
+            let row = &u[upper::base::get_row_start_index(i, n)..][..n - i];
+            x[i] /= diagonal(row[0], diag);
+
+            let xi = x[i];
+            row[1..]
+                .iter()
+                .zip(x[i + 1..].iter_mut())
+                .for_each(|(a, x)| *x -= a * xi);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// Multiply the dense row-major matrix, `a`, by `x`.
+    fn mul(a: &[[f64; 3]; 3], x: &[f64]) -> Vec<f64> {
+        a.iter()
+            .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect()
+    }
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[rustfmt::skip]
+    const L: [f64; 6] = [
+        2.0,
+        1.0, 4.0,
+        3.0, 2.0, 5.0,
+    ];
+
+    #[rustfmt::skip]
+    const L_DENSE: [[f64; 3]; 3] = [
+        [2.0, 0.0, 0.0],
+        [1.0, 4.0, 0.0],
+        [3.0, 2.0, 5.0],
+    ];
+
+    #[rustfmt::skip]
+    const U: [f64; 6] = [
+        2.0, 1.0, 3.0,
+             4.0, 2.0,
+                  5.0,
+    ];
+
+    #[rustfmt::skip]
+    const U_DENSE: [[f64; 3]; 3] = [
+        [2.0, 1.0, 3.0],
+        [0.0, 4.0, 2.0],
+        [0.0, 0.0, 5.0],
+    ];
+
+    fn transpose(a: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let mut t = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                t[j][i] = a[i][j];
+            }
+        }
+        t
+    }
+
+    #[test]
+    fn test_solve_lower() {
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&L_DENSE, &x);
+        solve_lower(&L, 3, Diagonal::NonUnit, &mut b).unwrap();
+
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_solve_lower_transpose() {
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&transpose(&L_DENSE), &x);
+        solve_lower_transpose(&L, 3, Diagonal::NonUnit, &mut b).unwrap();
+
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_solve_upper() {
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&U_DENSE, &x);
+        solve_upper(&U, 3, Diagonal::NonUnit, &mut b).unwrap();
+
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_solve_upper_transpose() {
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&transpose(&U_DENSE), &x);
+        solve_upper_transpose(&U, 3, Diagonal::NonUnit, &mut b).unwrap();
+
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_solve_unit_diagonal() {
+        let mut unit = L_DENSE;
+        (0..3).for_each(|i| unit[i][i] = 1.0);
+
+        let x = [1.0, -2.0, 0.5];
+        let mut b = mul(&unit, &x);
+        solve_lower(&L, 3, Diagonal::Unit, &mut b).unwrap();
+        assert_close(&b, &x);
+
+        let mut b = mul(&transpose(&unit), &x);
+        solve_lower_transpose(&L, 3, Diagonal::Unit, &mut b).unwrap();
+        assert_close(&b, &x);
+
+        let mut unit = U_DENSE;
+        (0..3).for_each(|i| unit[i][i] = 1.0);
+
+        let mut b = mul(&unit, &x);
+        solve_upper(&U, 3, Diagonal::Unit, &mut b).unwrap();
+        assert_close(&b, &x);
+
+        let mut b = mul(&transpose(&unit), &x);
+        solve_upper_transpose(&U, 3, Diagonal::Unit, &mut b).unwrap();
+        assert_close(&b, &x);
+    }
+
+    #[test]
+    fn test_solve_many() {
+        let (x, y) = ([1.0, -2.0, 0.5], [0.0, 3.0, -1.0]);
+        let mut b = [mul(&U_DENSE, &x), mul(&U_DENSE, &y)].concat();
+        solve_upper(&U, 3, Diagonal::NonUnit, &mut b).unwrap();
+
+        assert_close(&b, &[x, y].concat());
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let mut b = [1.0; 4];
+        assert_eq!(
+            solve_lower(&L, 3, Diagonal::NonUnit, &mut b),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            solve_upper(&L[..5], 3, Diagonal::NonUnit, &mut b[..3]),
+            Err(Error::InvalidLength {
+                expected: 6,
+                actual: 5
+            })
+        );
+
+        let mut singular = U;
+        singular[3] = 0.0;
+        assert_eq!(
+            solve_upper(&singular, 3, Diagonal::NonUnit, &mut b[..3]),
+            Err(Error::Singular { index: 1 })
+        );
+        assert!(solve_upper(&singular, 3, Diagonal::Unit, &mut b[..3]).is_ok());
+    }
+
+    #[test]
+    fn test_solve_singular_unchanged() {
+        // Each zero diagonal element is reached after earlier rows are solved.
+        let b = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut x = b;
+
+        let mut singular = L;
+        singular[5] = 0.0;
+        assert_eq!(
+            solve_lower(&singular, 3, Diagonal::NonUnit, &mut x),
+            Err(Error::Singular { index: 2 })
+        );
+        assert_eq!(x, b);
+
+        let mut singular = L;
+        singular[0] = 0.0;
+        assert_eq!(
+            solve_lower_transpose(&singular, 3, Diagonal::NonUnit, &mut x),
+            Err(Error::Singular { index: 0 })
+        );
+        assert_eq!(x, b);
+
+        let mut singular = U;
+        singular[0] = 0.0;
+        assert_eq!(
+            solve_upper(&singular, 3, Diagonal::NonUnit, &mut x),
+            Err(Error::Singular { index: 0 })
+        );
+        assert_eq!(x, b);
+
+        let mut singular = U;
+        singular[5] = 0.0;
+        assert_eq!(
+            solve_upper_transpose(&singular, 3, Diagonal::NonUnit, &mut x),
+            Err(Error::Singular { index: 2 })
+        );
+        assert_eq!(x, b);
+    }
+}
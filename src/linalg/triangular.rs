@@ -0,0 +1,347 @@
+//! Multiplication, inversion and determinants of packed triangle matrices.
+//!
+//! Dense operands, `b`, are stored in consecutive columns of length `n`.
+use super::{validate_cols_len, validate_vec_len, Diagonal};
+use crate::ops::validate_packed_len;
+use crate::{lower, upper, Error};
+
+/// Get the index of element `(i, j)`, where `i <= j`, of an upper packed triangle.
+fn upper_index(i: usize, j: usize, n: usize) -> usize {
+    upper::base::get_element_index(i, j - i, n)
+}
+
+/// Get the index of element `(i, j)`, where `j <= i`, of a lower packed triangle.
+fn lower_index(i: usize, j: usize) -> usize {
+    lower::base::get_element_index(i, j)
+}
+
+/// Get the diagonal element, or `1.0` for a unit diagonal.
+fn diagonal(el: f64, diag: Diagonal) -> f64 {
+    match diag {
+        Diagonal::Unit => 1.0,
+        Diagonal::NonUnit => el,
+    }
+}
+
+/// Calculate `x := U x` in place, where `U` is an upper packed triangle.
+pub fn tpmv_upper(u: &[f64], n: usize, diag: Diagonal, x: &mut [f64]) -> Result<(), Error> {
+    validate_vec_len(n, x.len())?;
+
+    tpmm_upper(u, n, diag, x)
+}
+
+/// Calculate `x := L x` in place, where `L` is a lower packed triangle.
+pub fn tpmv_lower(l: &[f64], n: usize, diag: Diagonal, x: &mut [f64]) -> Result<(), Error> {
+    validate_vec_len(n, x.len())?;
+
+    tpmm_lower(l, n, diag, x)
+}
+
+/// Calculate `B := U B` in place, where `U` is an upper packed triangle.
+pub fn tpmm_upper(u: &[f64], n: usize, diag: Diagonal, b: &mut [f64]) -> Result<(), Error> {
+    validate_packed_len(n, u.len())?;
+    validate_cols_len(n, b.len())?;
+
+    for x in b.chunks_exact_mut(n) {
+        // Row `i` only reads elements `j >= i`, which are yet to be written.
+        for i in 0..n {
+            let row = &u[upper::base::get_row_start_index(i, n)..][..n - i];
+            let sum = row[1..]
+                .iter()
+                .zip(x[i + 1..].iter())
+                .map(|(a, x)| a * x)
+                .sum::<f64>();
+
+            x[i] = diagonal(row[0], diag) * x[i] + sum;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculate `B := L B` in place, where `L` is a lower packed triangle.
+pub fn tpmm_lower(l: &[f64], n: usize, diag: Diagonal, b: &mut [f64]) -> Result<(), Error> {
+    validate_packed_len(n, l.len())?;
+    validate_cols_len(n, b.len())?;
+
+    for x in b.chunks_exact_mut(n) {
+        // Row `i` only reads elements `j <= i`, which are yet to be written.
+        for i in (0..n).rev() {
+            let row = &l[lower::base::get_row_start_index(i)..][..=i];
+            let sum = row[..i]
+                .iter()
+                .zip(x[..i].iter())
+                .map(|(a, x)| a * x)
+                .sum::<f64>();
+
+            x[i] = diagonal(row[i], diag) * x[i] + sum;
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculate the upper packed triangle `A B`, where `A` and `B` are upper packed
+/// triangles.
+pub fn mul_upper(a: &[f64], b: &[f64], n: usize) -> Result<Vec<f64>, Error> {
+    validate_packed_len(n, a.len())?;
+    validate_packed_len(n, b.len())?;
+
+    Ok(upper::base::iter_triangle_indices(n)
+        .map(|(i, j)| {
+            (i..=j)
+                .map(|k| a[upper_index(i, k, n)] * b[upper_index(k, j, n)])
+                .sum()
+        })
+        .collect())
+}
+
+/// Calculate the lower packed triangle `A B`, where `A` and `B` are lower packed
+/// triangles.
+pub fn mul_lower(a: &[f64], b: &[f64], n: usize) -> Result<Vec<f64>, Error> {
+    validate_packed_len(n, a.len())?;
+    validate_packed_len(n, b.len())?;
+
+    Ok(lower::base::iter_triangle_indices(n)
+        .map(|(i, j)| {
+            (j..=i)
+                .map(|k| a[lower_index(i, k)] * b[lower_index(k, j)])
+                .sum()
+        })
+        .collect())
+}
+
+/// Invert an upper packed triangle, `u`, in place.
+///
+/// Returns an [`Error::Singular`] if a diagonal element is zero, leaving `u`
+/// partially inverted.
+pub fn invert_upper(u: &mut [f64], n: usize, diag: Diagonal) -> Result<(), Error> {
+    validate_packed_len(n, u.len())?;
+
+    // Column `j` of the inverse is `-U⁻¹[..j, ..j] U[..j, j] / U[j, j]`, where
+    // the leading `j × j` block is already inverted.
+    let mut x = vec![0.0; n];
+    for j in 0..n {
+        let ajj = match diag {
+            Diagonal::Unit => -1.0,
+            Diagonal::NonUnit => {
+                let el = &mut u[upper_index(j, j, n)];
+                if *el == 0.0 {
+                    return Err(Error::Singular { index: j });
+                }
+                *el = 1.0 / *el;
+                -*el
+            }
+        };
+
+        (0..j).for_each(|k| x[k] = u[upper_index(k, j, n)]);
+        for i in 0..j {
+            x[i] = diagonal(u[upper_index(i, i, n)], diag) * x[i]
+                + (i + 1..j)
+                    .map(|k| u[upper_index(i, k, n)] * x[k])
+                    .sum::<f64>();
+            u[upper_index(i, j, n)] = ajj * x[i];
+        }
+    }
+
+    Ok(())
+}
+
+/// Invert a lower packed triangle, `l`, in place.
+///
+/// Returns an [`Error::Singular`] if a diagonal element is zero, leaving `l`
+/// partially inverted.
+pub fn invert_lower(l: &mut [f64], n: usize, diag: Diagonal) -> Result<(), Error> {
+    validate_packed_len(n, l.len())?;
+
+    // Column `j` of the inverse is `-L⁻¹[j + 1.., j + 1..] L[j + 1.., j] / L[j, j]`,
+    // where the trailing block is already inverted.
+    let mut x = vec![0.0; n];
+    for j in (0..n).rev() {
+        let ajj = match diag {
+            Diagonal::Unit => -1.0,
+            Diagonal::NonUnit => {
+                let el = &mut l[lower_index(j, j)];
+                if *el == 0.0 {
+                    return Err(Error::Singular { index: j });
+                }
+                *el = 1.0 / *el;
+                -*el
+            }
+        };
+
+        (j + 1..n).for_each(|k| x[k] = l[lower_index(k, j)]);
+        for i in (j + 1..n).rev() {
+            x[i] = diagonal(l[lower_index(i, i)], diag) * x[i]
+                + (j + 1..i).map(|k| l[lower_index(i, k)] * x[k]).sum::<f64>();
+            l[lower_index(i, j)] = ajj * x[i];
+        }
+    }
+
+    Ok(())
+}
+
+/// Calculate the determinant of an upper packed triangle, `u`, as the product
+/// of the diagonal.
+pub fn determinant_upper(u: &[f64], n: usize) -> Result<f64, Error> {
+    validate_packed_len(n, u.len())?;
+
+    Ok((0..n).map(|i| u[upper_index(i, i, n)]).product())
+}
+
+/// Calculate the determinant of a lower packed triangle, `l`, as the product
+/// of the diagonal.
+pub fn determinant_lower(l: &[f64], n: usize) -> Result<f64, Error> {
+    validate_packed_len(n, l.len())?;
+
+    Ok((0..n).map(|i| l[lower_index(i, i)]).product())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[rustfmt::skip]
+    const UPPER: [f64; 6] = [
+        2.0, 1.0, 3.0,
+             4.0, 5.0,
+                  -1.0,
+    ];
+
+    #[rustfmt::skip]
+    const LOWER: [f64; 6] = [
+        2.0,
+        1.0, 4.0,
+        3.0, 5.0, -1.0,
+    ];
+
+    #[rustfmt::skip]
+    const IDENTITY_UPPER: [f64; 6] = [
+        1.0, 0.0, 0.0,
+             1.0, 0.0,
+                  1.0,
+    ];
+
+    #[rustfmt::skip]
+    const IDENTITY_LOWER: [f64; 6] = [
+        1.0,
+        0.0, 1.0,
+        0.0, 0.0, 1.0,
+    ];
+
+    #[test]
+    fn test_tpmv() {
+        let mut x = [1.0, 2.0, 3.0];
+        tpmv_upper(&UPPER, 3, Diagonal::NonUnit, &mut x).unwrap();
+        assert_eq!(x, [13.0, 23.0, -3.0]);
+
+        let mut x = [1.0, 2.0, 3.0];
+        tpmv_lower(&LOWER, 3, Diagonal::NonUnit, &mut x).unwrap();
+        assert_eq!(x, [2.0, 9.0, 10.0]);
+
+        let mut x = [1.0, 2.0, 3.0];
+        tpmv_upper(&UPPER, 3, Diagonal::Unit, &mut x).unwrap();
+        assert_eq!(x, [12.0, 17.0, 3.0]);
+
+        assert_eq!(
+            tpmv_lower(&LOWER, 3, Diagonal::NonUnit, &mut [1.0; 6]),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_tpmm() {
+        let mut b = [1.0, 2.0, 3.0, 0.0, 1.0, 0.0];
+        tpmm_upper(&UPPER, 3, Diagonal::NonUnit, &mut b).unwrap();
+        assert_eq!(b, [13.0, 23.0, -3.0, 1.0, 4.0, 0.0]);
+
+        let mut b = [1.0, 2.0, 3.0, 0.0, 1.0, 0.0];
+        tpmm_lower(&LOWER, 3, Diagonal::NonUnit, &mut b).unwrap();
+        assert_eq!(b, [2.0, 9.0, 10.0, 0.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_mul() {
+        #[rustfmt::skip]
+        let expected = [
+            4.0, 6.0, 8.0,
+                 16.0, 15.0,
+                       1.0,
+        ];
+        assert_eq!(mul_upper(&UPPER, &UPPER, 3).unwrap(), expected);
+
+        #[rustfmt::skip]
+        let expected = [
+            4.0,
+            6.0, 16.0,
+            8.0, 15.0, 1.0,
+        ];
+        assert_eq!(mul_lower(&LOWER, &LOWER, 3).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_invert() {
+        let mut inverse = UPPER;
+        invert_upper(&mut inverse, 3, Diagonal::NonUnit).unwrap();
+        assert_close(&mul_upper(&UPPER, &inverse, 3).unwrap(), &IDENTITY_UPPER);
+
+        let mut inverse = LOWER;
+        invert_lower(&mut inverse, 3, Diagonal::NonUnit).unwrap();
+        assert_close(&mul_lower(&LOWER, &inverse, 3).unwrap(), &IDENTITY_LOWER);
+    }
+
+    #[test]
+    fn test_invert_unit() {
+        // Set the unused diagonal to values which would otherwise be singular.
+        let unit = |mut a: [f64; 6], diagonal: [usize; 3]| {
+            diagonal.iter().for_each(|k| a[*k] = 1.0);
+            a
+        };
+
+        let mut inverse = UPPER;
+        inverse[0] = 0.0;
+        invert_upper(&mut inverse, 3, Diagonal::Unit).unwrap();
+        let (a, inverse) = (unit(UPPER, [0, 3, 5]), unit(inverse, [0, 3, 5]));
+        assert_close(&mul_upper(&a, &inverse, 3).unwrap(), &IDENTITY_UPPER);
+
+        let mut inverse = LOWER;
+        inverse[0] = 0.0;
+        invert_lower(&mut inverse, 3, Diagonal::Unit).unwrap();
+        let (a, inverse) = (unit(LOWER, [0, 2, 5]), unit(inverse, [0, 2, 5]));
+        assert_close(&mul_lower(&a, &inverse, 3).unwrap(), &IDENTITY_LOWER);
+    }
+
+    #[test]
+    fn test_invert_singular() {
+        let mut u = UPPER;
+        u[3] = 0.0;
+        assert_eq!(
+            invert_upper(&mut u, 3, Diagonal::NonUnit),
+            Err(Error::Singular { index: 1 })
+        );
+
+        let mut l = LOWER;
+        l[2] = 0.0;
+        assert_eq!(
+            invert_lower(&mut l, 3, Diagonal::NonUnit),
+            Err(Error::Singular { index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(determinant_upper(&UPPER, 3), Ok(-8.0));
+        assert_eq!(determinant_lower(&LOWER, 3), Ok(-8.0));
+    }
+}
@@ -0,0 +1,148 @@
+//! A Hermitian lower triangle matrix abstraction.
+use std::ops::DerefMut;
+
+use super::symmetric::{SymmetricLowerTri, SymmetricLowerTriMut};
+use crate::ops::{oriented, oriented_row};
+use crate::{Col, Conjugate, Row, SimpleLower, Triangle, TriangleMut};
+
+/// A Hermitian lower triangle collection.
+///
+/// Contains `tri_num(n)` elements with `n - 1` rows and columns to account for
+/// the diagonal.
+///
+/// For all indices `i` and `j` where `i != j`, the element `(j, i)` is the
+/// complex conjugate of the element `(i, j)`. Elements are stored in the
+/// orientation `j < i`, and returned by value. Indexing, iteration and
+/// validation of the stored elements are provided by [`SymmetricLowerTri`].
+///
+/// The diagonal, which is real for a Hermitian matrix, is neither stored nor
+/// checked, and must be kept separately where required.
+pub trait HermitianLowerTri<T>: SymmetricLowerTri<T> {
+    /// Get an element, conjugated where `i < j`.
+    fn get_element(&self, i: impl Into<Row>, j: impl Into<Col>) -> T
+    where
+        T: Copy + Conjugate,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let el = *SymmetricLowerTri::get_element(self, i, j);
+        oriented::<SimpleLower, _>(i, j, el, |el| el.conj())
+    }
+
+    /// Get an iterator of the elements of a row, conjugated where `i < j`.
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Conjugate + 'a,
+    {
+        let Row(i) = i.into();
+
+        let row = SymmetricLowerTri::get_row(self, i);
+        oriented_row::<SimpleLower, _>(self.n(), i, row, |el| el.conj())
+    }
+
+    /// Get an iterator of the elements of a column, conjugated where `i < j`.
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Conjugate + 'a,
+    {
+        let Col(j) = j.into();
+
+        HermitianLowerTri::get_row(self, j).map(|el| el.conj())
+    }
+}
+
+impl<T, U: Triangle<T>> HermitianLowerTri<T> for U {}
+
+pub trait HermitianLowerTriMut<T>: SymmetricLowerTriMut<T>
+where
+    Self::Inner: DerefMut<Target = [T]>,
+{
+    /// Set an element, storing the conjugate of `value` where `i < j`.
+    fn set_element(&mut self, i: impl Into<Row>, j: impl Into<Col>, value: T)
+    where
+        T: Conjugate,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        *SymmetricLowerTriMut::get_element_mut(self, i, j) =
+            oriented::<SimpleLower, _>(i, j, value, |el| el.conj());
+    }
+}
+
+impl<T, U: Triangle<T> + TriangleMut<T>> HermitianLowerTriMut<T> for U where
+    Self::Inner: DerefMut<Target = [T]>
+{
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{HermitianLowerTri, HermitianLowerTriMut};
+    use crate::{Complex, Triangle, TriangleMut};
+
+    struct LoTriVec(usize, Vec<Complex<i32>>);
+
+    impl Triangle<Complex<i32>> for LoTriVec {
+        type Inner = Vec<Complex<i32>>;
+
+        fn n(&self) -> usize {
+            self.0
+        }
+
+        fn inner(&self) -> &Vec<Complex<i32>> {
+            &self.1
+        }
+    }
+
+    impl TriangleMut<Complex<i32>> for LoTriVec {
+        fn inner_mut(&mut self) -> &mut Vec<Complex<i32>> {
+            &mut self.1
+        }
+    }
+
+    fn c(re: i32, im: i32) -> Complex<i32> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn test_get_element() {
+        let m = LoTriVec(3, vec![c(1, 1), c(2, -2), c(3, 3)]);
+
+        assert_eq!(m.get_element(1, 0), c(1, 1));
+        assert_eq!(m.get_element(0, 1), c(1, -1));
+        assert_eq!(m.get_element(2, 0), c(2, -2));
+        assert_eq!(m.get_element(0, 2), c(2, 2));
+        assert_eq!(m.get_element(1, 2), c(3, -3));
+    }
+
+    #[test]
+    fn test_get_row_col() {
+        let m = LoTriVec(3, vec![c(1, 1), c(2, -2), c(3, 3)]);
+
+        assert_eq!(m.get_row(0).collect::<Vec<_>>(), [c(1, -1), c(2, 2)]);
+        assert_eq!(m.get_row(1).collect::<Vec<_>>(), [c(1, 1), c(3, -3)]);
+        assert_eq!(m.get_row(2).collect::<Vec<_>>(), [c(2, -2), c(3, 3)]);
+        assert_eq!(m.get_col(0).collect::<Vec<_>>(), [c(1, 1), c(2, -2)]);
+    }
+
+    #[test]
+    fn test_set_element() {
+        let mut m = LoTriVec(3, vec![c(0, 0); 3]);
+        m.set_element(1, 0, c(1, 2));
+        m.set_element(1, 2, c(3, 4));
+
+        assert_eq!(m.inner(), &[c(1, 2), c(0, 0), c(3, -4)]);
+        assert_eq!(m.get_element(1, 2), c(3, 4));
+    }
+
+    #[test]
+    fn test_real() {
+        let m = crate::Tri::<crate::SymmetricLower, _>::new(3, vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(HermitianLowerTri::get_element(&m, 0, 2), 2.0);
+        assert_eq!(
+            HermitianLowerTri::get_row(&m, 1).collect::<Vec<_>>(),
+            [1.0, 3.0]
+        );
+    }
+}
@@ -1,8 +1,16 @@
 //! Lower triangle traits.
 pub mod base;
 
+mod hermitian;
+// The baseline accessors keep explicit lifetimes.
+#[allow(clippy::needless_lifetimes)]
 mod simple;
+mod skew;
+// The baseline accessors keep explicit lifetimes.
+#[allow(clippy::needless_lifetimes)]
 mod symmetric;
 
+pub use hermitian::{HermitianLowerTri, HermitianLowerTriMut};
 pub use simple::{SimpleLowerTri, SimpleLowerTriMut};
+pub use skew::{SkewLowerTri, SkewLowerTriMut};
 pub use symmetric::{SymmetricLowerTri, SymmetricLowerTriMut};
@@ -1,8 +1,12 @@
 //! A simple upper triangle abstraction.
+use std::cmp::Ordering;
 use std::ops::DerefMut;
 
 use super::base;
-use crate::{Triangle, TriangleMut};
+use crate::diagonal::ConstDiagonal;
+use crate::display::TriDisplay;
+use crate::ops::{select_by_key, validate_len};
+use crate::{Col, Error, Pair, Row, SimpleLower, Triangle, TriangleMut};
 
 /// A simple lower triangle collection.
 ///
@@ -10,11 +14,16 @@ use crate::{Triangle, TriangleMut};
 /// the diagonal.
 ///
 /// Any index outside of the lower triangle will cause a panic.
+///
+/// Accessors require a non-empty triangle, `n > 0`, with `tri_num(n - 1)` elements.
+/// Only `validate`, `validated` and `iter_triangle_indices` accept an empty axis.
 pub trait SimpleLowerTri<T>: Triangle<T> {
     /// Get a reference to an element.
-    fn get_element<'a>(&'a self, i: usize, j: usize) -> &'a T {
-        debug_assert!(i <= self.n() - 1);
-        debug_assert!(j <= self.n() - 1);
+    fn get_element<'a>(&'a self, i: impl Into<Row>, j: impl Into<Col>) -> &'a T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
 
         assert!(i != 0);
         assert!(j < i);
@@ -23,8 +32,15 @@ pub trait SimpleLowerTri<T>: Triangle<T> {
         &self.inner()[index]
     }
 
+    /// Get a reference to the element at a pair of indices.
+    fn get_pair(&self, pair: impl Into<Pair>) -> &T {
+        let Pair { i, j } = pair.into();
+
+        SimpleLowerTri::get_element(self, i, j)
+    }
+
     /// Get an iterator of references to elements of a row.
-    fn get_row<'a>(&'a self, i: usize) -> impl Iterator<Item = &'a T>
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = &'a T>
     where
         T: 'a,
     {
@@ -32,31 +48,37 @@ pub trait SimpleLowerTri<T>: Triangle<T> {
     }
 
     /// Get an iterator of references to elements of a col.
-    fn get_col<'a>(&'a self, i: usize) -> impl Iterator<Item = &'a T>
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = &'a T>
     where
         T: 'a,
     {
-        SimpleLowerTri::get_col_indices(self, i).map(|el| &self.inner()[el])
+        SimpleLowerTri::get_col_indices(self, j).map(|el| &self.inner()[el])
     }
 
     /// Get the first index of a row.
-    fn get_row_start_index(&self, i: usize) -> usize {
-        debug_assert!(i <= self.n() - 1);
+    fn get_row_start_index(&self, i: impl Into<Row>) -> usize {
+        let Row(i) = i.into();
+
+        debug_assert!(i < self.n());
 
         assert!(i != 0);
         base::get_row_start_index(i - 1)
     }
 
     /// Get the first index of a column.
-    fn get_col_start_index(&self, j: usize) -> usize {
-        debug_assert!(j <= self.n() - 1);
+    fn get_col_start_index(&self, j: impl Into<Col>) -> usize {
+        let Col(j) = j.into();
+
+        debug_assert!(j < self.n());
 
         base::get_col_start_index(j)
     }
 
     /// Get all indices of a row.
-    fn get_row_indices<'a, 'b>(&'a self, i: usize) -> impl Iterator<Item = usize> + 'b {
-        debug_assert!(i <= self.n() - 1);
+    fn get_row_indices<'a, 'b>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = usize> + 'b {
+        let Row(i) = i.into();
+
+        debug_assert!(i < self.n());
 
         assert!(i != 0);
 
@@ -64,15 +86,152 @@ pub trait SimpleLowerTri<T>: Triangle<T> {
     }
 
     /// Get all indices of a column.
-    fn get_col_indices<'a, 'b>(&'a self, j: usize) -> impl Iterator<Item = usize> + 'b {
-        debug_assert!(j <= self.n() - 1);
+    fn get_col_indices<'a, 'b>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = usize> + 'b {
+        let Col(j) = j.into();
+
+        debug_assert!(j < self.n());
 
         base::get_col_indices(j, self.n() - 1)
     }
 
     /// Iterate all `(i, j)` indices of the triangle.
     fn iter_triangle_indices<'a, 'b>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'b {
-        base::iter_triangle_indices(self.n() - 1).map(|(i, j)| (i + 1, j))
+        base::iter_triangle_indices(self.n().saturating_sub(1)).map(|(i, j)| (i + 1, j))
+    }
+
+    /// Iterate all `(i, j)` indices and references to elements of the triangle,
+    /// in storage order.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        SimpleLowerTri::iter_triangle_indices(self)
+            .zip(self.inner().iter())
+            .map(|((i, j), el)| (i, j, el))
+    }
+
+    /// Get the indices of, and a reference to, the smallest element. Ignores
+    /// incomparable elements such as `NaN`, and returns the first of equal elements.
+    fn argmin<'a>(&'a self) -> Option<(usize, usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SimpleLowerTri::argmin_by_key(self, |el| el)
+    }
+
+    /// Get the indices of, and a reference to, the largest element. Ignores
+    /// incomparable elements such as `NaN`, and returns the first of equal elements.
+    fn argmax<'a>(&'a self) -> Option<(usize, usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SimpleLowerTri::argmax_by_key(self, |el| el)
+    }
+
+    /// Get the indices of, and a reference to, the element with the smallest key.
+    /// Ignores incomparable keys such as `NaN`, and returns the first of equal keys.
+    fn argmin_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        select_by_key(
+            SimpleLowerTri::iter(self),
+            |(_, _, el)| f(el),
+            Ordering::Less,
+        )
+    }
+
+    /// Get the indices of, and a reference to, the element with the largest key.
+    /// Ignores incomparable keys such as `NaN`, and returns the first of equal keys.
+    fn argmax_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        select_by_key(
+            SimpleLowerTri::iter(self),
+            |(_, _, el)| f(el),
+            Ordering::Greater,
+        )
+    }
+
+    /// Get a reference to an element without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `j < i < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_element_unchecked(&self, i: impl Into<Row>, j: impl Into<Col>) -> &T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let index = base::get_element_index(i - 1, j);
+
+        self.inner().get_unchecked(index)
+    }
+
+    /// Get an iterator of references to elements of a row without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `0 < i < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_row_unchecked<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let Row(i) = i.into();
+
+        base::get_row_indices(i - 1).map(|el| unsafe { self.inner().get_unchecked(el) })
+    }
+
+    /// Get an iterator of references to elements of a col without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `j < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_col_unchecked<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let Col(j) = j.into();
+
+        base::get_col_indices(j, self.n() - 1).map(|el| unsafe { self.inner().get_unchecked(el) })
+    }
+
+    /// Get a formatter adapter displaying the triangle matrix.
+    fn display(&self) -> TriDisplay<'_, SimpleLower, Self, T>
+    where
+        Self: Sized,
+    {
+        TriDisplay::new(self)
+    }
+
+    /// Get a view of the triangle matrix with the constant `diagonal`, getting
+    /// full rows and columns of `n` elements.
+    fn with_diagonal(&self, diagonal: T) -> ConstDiagonal<'_, SimpleLower, Self, T>
+    where
+        Self: Sized,
+        T: Default,
+    {
+        ConstDiagonal::new(self, diagonal)
+    }
+
+    /// Validate the length of the inner collection against the axis length, `n`.
+    fn validate(&self) -> Result<(), Error> {
+        validate_len(self.n(), self.inner().len())
+    }
+
+    /// Validate the triangle, returning it if the inner collection length
+    /// matches the axis length, `n`.
+    fn validated(self) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        SimpleLowerTri::validate(&self)?;
+        Ok(self)
     }
 }
 
@@ -83,9 +242,11 @@ where
     Self::Inner: DerefMut<Target = [T]>,
 {
     /// Get a mutable reference to an element.
-    fn get_element_mut<'a>(&'a mut self, i: usize, j: usize) -> &'a mut T {
-        debug_assert!(i <= self.n() - 1);
-        debug_assert!(j <= self.n() - 1);
+    fn get_element_mut<'a>(&'a mut self, i: impl Into<Row>, j: impl Into<Col>) -> &'a mut T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
 
         assert!(i != 0);
         assert!(j < i);
@@ -93,6 +254,38 @@ where
         let index = base::get_element_index(i - 1, j);
         &mut self.inner_mut().deref_mut()[index]
     }
+
+    /// Get a mutable reference to the element at a pair of indices.
+    fn get_pair_mut(&mut self, pair: impl Into<Pair>) -> &mut T {
+        let Pair { i, j } = pair.into();
+
+        SimpleLowerTriMut::get_element_mut(self, i, j)
+    }
+
+    /// Get a mutable reference to an element without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `j < i < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_element_unchecked_mut(&mut self, i: impl Into<Row>, j: impl Into<Col>) -> &mut T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let index = base::get_element_index(i - 1, j);
+
+        self.inner_mut().get_unchecked_mut(index)
+    }
+
+    /// Iterate all `(i, j)` indices and mutable references to elements of the
+    /// triangle, in storage order.
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        base::iter_triangle_indices(self.n().saturating_sub(1))
+            .map(|(i, j)| (i + 1, j))
+            .zip(self.inner_mut().iter_mut())
+            .map(|((i, j), el)| (i, j, el))
+    }
 }
 
 impl<T, U: Triangle<T> + TriangleMut<T>> SimpleLowerTriMut<T> for U where
@@ -105,8 +298,8 @@ mod tests {
 
     mod lower_triangle {
 
+        use crate::{Error, Triangle, TriangleMut};
         use crate::{SimpleLowerTri, SimpleLowerTriMut};
-        use crate::{Triangle, TriangleMut};
 
         struct LoTriVec(usize, Vec<usize>);
 
@@ -280,6 +473,9 @@ mod tests {
 
         #[test]
         fn test_iter_triangle_indices() {
+            assert_eq!(LoTriVec(0, Vec::new()).iter_triangle_indices().count(), 0);
+            assert_eq!(LoTriVec(1, Vec::new()).iter_triangle_indices().count(), 0);
+
             let n = 5;
             let m = LoTriVec(n, Vec::new());
 
@@ -291,5 +487,165 @@ mod tests {
                 (4, 0), (4, 1), (4, 2), (4, 3)
             ]);
         }
+
+        #[test]
+        fn test_validate() {
+            assert_eq!(LoTriVec(5, vec![0; 10]).validate(), Ok(()));
+            assert_eq!(LoTriVec(1, vec![]).validate(), Ok(()));
+            assert_eq!(LoTriVec(0, vec![]).validate(), Err(Error::EmptyAxis));
+            assert_eq!(
+                LoTriVec(4, vec![0; 10]).validate(),
+                Err(Error::InvalidLength {
+                    expected: 6,
+                    actual: 10
+                })
+            );
+
+            assert!(LoTriVec(5, vec![0; 10]).validated().is_ok());
+            assert!(LoTriVec(5, vec![0; 9]).validated().is_err());
+        }
+
+        #[test]
+        fn test_get_element_unchecked() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+                6, 7, 8, 9,
+            ];
+            let n = 5;
+            let m = LoTriVec(n, v);
+
+            for (i, j) in m.iter_triangle_indices() {
+                assert_eq!(
+                    unsafe { m.get_element_unchecked(i, j) },
+                    m.get_element(i, j)
+                );
+            }
+        }
+
+        #[test]
+        fn test_get_element_unchecked_mut() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+                6, 7, 8, 9,
+            ];
+            let n = 5;
+            let mut m = LoTriVec(n, v);
+            let pairs = m.iter_triangle_indices().collect::<Vec<_>>();
+
+            for (k, &(i, j)) in pairs.iter().enumerate() {
+                unsafe { *m.get_element_unchecked_mut(i, j) = k + 10 };
+                assert_eq!(*m.get_element(i, j), k + 10);
+            }
+        }
+
+        #[test]
+        fn test_get_row_unchecked() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+                6, 7, 8, 9,
+            ];
+            let n = 5;
+            let m = LoTriVec(n, v);
+
+            for i in 1..n {
+                assert!(unsafe { m.get_row_unchecked(i) }.eq(m.get_row(i)));
+            }
+        }
+
+        #[test]
+        fn test_get_col_unchecked() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+                6, 7, 8, 9,
+            ];
+            let n = 5;
+            let m = LoTriVec(n, v);
+
+            for j in 0..n - 1 {
+                assert!(unsafe { m.get_col_unchecked(j) }.eq(m.get_col(j)));
+            }
+        }
+
+        #[test]
+        fn test_display() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+            ];
+            let m = LoTriVec(4, v);
+
+            assert_eq!(m.display().to_string(), "\n0\n1 2\n3 4 5");
+        }
+
+        #[test]
+        fn test_iter() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+                6, 7, 8, 9,
+            ];
+            let n = 5;
+            let m = LoTriVec(n, v);
+
+            assert_eq!(m.iter().count(), 10);
+            for (k, (i, j, el)) in m.iter().enumerate() {
+                assert_eq!(*el, k);
+                assert_eq!(m.get_element(i, j), el);
+            }
+        }
+
+        #[test]
+        fn test_iter_mut() {
+            #[rustfmt::skip]
+            let v = vec![
+                0,
+                1, 2,
+                3, 4, 5,
+                6, 7, 8, 9,
+            ];
+            let n = 5;
+            let mut m = LoTriVec(n, v);
+
+            for (i, j, el) in m.iter_mut() {
+                *el = i * 10 + j;
+            }
+            for (i, j) in m.iter_triangle_indices() {
+                assert_eq!(*m.get_element(i, j), i * 10 + j);
+            }
+        }
+
+        #[test]
+        fn test_argmin_argmax() {
+            #[rustfmt::skip]
+            let v = vec![
+                3,
+                1, 2,
+                3, 0, 6,
+                7, 9, 9, 1,
+            ];
+            let n = 5;
+            let m = LoTriVec(n, v);
+
+            assert_eq!(m.argmin(), Some((3, 1, &0)));
+            assert_eq!(m.argmax(), Some((4, 1, &9)));
+            assert_eq!(m.argmin_by_key(|el| el.abs_diff(5)), Some((3, 2, &6)));
+            assert_eq!(m.argmax_by_key(|el| el % 4), Some((1, 0, &3)));
+        }
     }
 }
@@ -0,0 +1,169 @@
+//! A skew-symmetric lower triangle matrix abstraction.
+use std::ops::{DerefMut, Neg};
+
+use super::symmetric::{SymmetricLowerTri, SymmetricLowerTriMut};
+use crate::ops::{mirrored_dense, oriented, oriented_row};
+use crate::{Col, Row, SimpleLower, Triangle, TriangleMut};
+
+/// A skew-symmetric lower triangle collection.
+///
+/// Contains `tri_num(n)` elements with `n - 1` rows and columns to account for
+/// the diagonal.
+///
+/// For all indices `i` and `j` where `i != j`, the element `(j, i)` is the
+/// negation of the element `(i, j)`. Elements are stored in the orientation
+/// `j < i`, and returned by value. Indexing, iteration and validation of the
+/// stored elements are provided by [`SymmetricLowerTri`].
+pub trait SkewLowerTri<T>: SymmetricLowerTri<T> {
+    /// Get an element, negated where `i < j`.
+    fn get_element(&self, i: impl Into<Row>, j: impl Into<Col>) -> T
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        oriented::<SimpleLower, _>(i, j, *SymmetricLowerTri::get_element(self, i, j), T::neg)
+    }
+
+    /// Get an iterator of the signed elements of a row.
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Neg<Output = T> + 'a,
+    {
+        let Row(i) = i.into();
+
+        oriented_row::<SimpleLower, _>(self.n(), i, SymmetricLowerTri::get_row(self, i), T::neg)
+    }
+
+    /// Get an iterator of the signed elements of a column.
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Neg<Output = T> + 'a,
+    {
+        let Col(j) = j.into();
+
+        SkewLowerTri::get_row(self, j).map(T::neg)
+    }
+
+    /// Export the full matrix as a row-major `n × n` collection, with zero
+    /// (`T::default()`) diagonal elements.
+    fn to_dense(&self) -> Vec<T>
+    where
+        T: Copy + Default + Neg<Output = T>,
+    {
+        let elements = SymmetricLowerTri::iter(self).map(|(i, j, el)| (i, j, *el));
+        mirrored_dense(self.n(), elements, T::neg)
+    }
+}
+
+impl<T, U: Triangle<T>> SkewLowerTri<T> for U {}
+
+pub trait SkewLowerTriMut<T>: SymmetricLowerTriMut<T>
+where
+    Self::Inner: DerefMut<Target = [T]>,
+{
+    /// Set an element, storing the negation of `value` where `i < j`.
+    fn set_element(&mut self, i: impl Into<Row>, j: impl Into<Col>, value: T)
+    where
+        T: Neg<Output = T>,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        *SymmetricLowerTriMut::get_element_mut(self, i, j) =
+            oriented::<SimpleLower, _>(i, j, value, T::neg);
+    }
+}
+
+impl<T, U: Triangle<T> + TriangleMut<T>> SkewLowerTriMut<T> for U where
+    Self::Inner: DerefMut<Target = [T]>
+{
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{SkewLowerTri, SkewLowerTriMut};
+    use crate::{Triangle, TriangleMut};
+
+    struct LoTriVec(usize, Vec<i32>);
+
+    impl Triangle<i32> for LoTriVec {
+        type Inner = Vec<i32>;
+
+        fn n(&self) -> usize {
+            self.0
+        }
+
+        fn inner(&self) -> &Vec<i32> {
+            &self.1
+        }
+    }
+
+    impl TriangleMut<i32> for LoTriVec {
+        fn inner_mut(&mut self) -> &mut Vec<i32> {
+            &mut self.1
+        }
+    }
+
+    #[test]
+    fn test_get_element() {
+        #[rustfmt::skip]
+        let v = vec![
+            1,
+            2, 3,
+            4, 5, 6,
+        ];
+
+        let m = LoTriVec(4, v);
+
+        assert_eq!(m.get_element(1, 0), 1);
+        assert_eq!(m.get_element(0, 1), -1);
+        assert_eq!(m.get_element(3, 1), 5);
+        assert_eq!(m.get_element(1, 3), -5);
+        assert_eq!(m.get_element(3, 2), 6);
+        assert_eq!(m.get_element(2, 3), -6);
+    }
+
+    #[test]
+    fn test_get_row_col() {
+        #[rustfmt::skip]
+        let v = vec![
+            1,
+            2, 3,
+            4, 5, 6,
+        ];
+
+        let m = LoTriVec(4, v);
+
+        assert_eq!(m.get_row(0).collect::<Vec<_>>(), [-1, -2, -4]);
+        assert_eq!(m.get_row(1).collect::<Vec<_>>(), [1, -3, -5]);
+        assert_eq!(m.get_row(2).collect::<Vec<_>>(), [2, 3, -6]);
+        assert_eq!(m.get_row(3).collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(m.get_col(0).collect::<Vec<_>>(), [1, 2, 4]);
+        assert_eq!(m.get_col(2).collect::<Vec<_>>(), [-2, -3, 6]);
+    }
+
+    #[test]
+    fn test_set_element() {
+        let mut m = LoTriVec(4, vec![0; 6]);
+        m.set_element(2, 0, 7);
+        m.set_element(1, 3, 8);
+
+        assert_eq!(m.inner(), &[0, 7, 0, 0, -8, 0]);
+        assert_eq!(m.get_element(0, 2), -7);
+        assert_eq!(m.get_element(1, 3), 8);
+    }
+
+    #[test]
+    fn test_to_dense() {
+        let m = LoTriVec(3, vec![1, 2, 3]);
+
+        #[rustfmt::skip]
+        let expected = [
+            0, -1, -2,
+            1,  0, -3,
+            2,  3,  0,
+        ];
+        assert_eq!(m.to_dense(), expected);
+    }
+}
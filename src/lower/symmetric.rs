@@ -1,8 +1,15 @@
 //! A symmetric lower triangle matrix abstraction.
-use std::ops::DerefMut;
+use std::cmp::Ordering;
+use std::ops::{AddAssign, DerefMut};
 
 use super::base;
-use crate::{Triangle, TriangleMut};
+use crate::diagonal::ConstDiagonal;
+use crate::display::TriDisplay;
+use crate::graph::TriangleGraph;
+use crate::linalg::mds::{self, Mds};
+use crate::ops::{select_by_key, validate_len};
+use crate::stats::ToF64;
+use crate::{Col, Error, Pair, Row, SymmetricLower, Triangle, TriangleMut};
 
 /// A symmetric lower triangle collection.
 ///
@@ -11,11 +18,16 @@ use crate::{Triangle, TriangleMut};
 ///
 /// For all indices `i` and `j` where `i != j`, all pairs of `(i, j)` are equal to
 /// the pair `(j, i)`.
+///
+/// Accessors require a non-empty triangle, `n > 0`, with `tri_num(n - 1)` elements.
+/// Only `validate`, `validated` and `iter_triangle_indices` accept an empty axis.
 pub trait SymmetricLowerTri<T>: Triangle<T> {
     /// Get a reference to an element.
-    fn get_element<'a>(&'a self, i: usize, j: usize) -> &'a T {
-        debug_assert!(i <= self.n() - 1);
-        debug_assert!(j <= self.n() - 1);
+    fn get_element<'a>(&'a self, i: impl Into<Row>, j: impl Into<Col>) -> &'a T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
 
         let index = if j < i {
             base::get_element_index(i - 1, j)
@@ -26,8 +38,15 @@ pub trait SymmetricLowerTri<T>: Triangle<T> {
         &self.inner()[index]
     }
 
+    /// Get a reference to the element at a pair of indices.
+    fn get_pair(&self, pair: impl Into<Pair>) -> &T {
+        let Pair { i, j } = pair.into();
+
+        SymmetricLowerTri::get_element(self, i, j)
+    }
+
     /// Get an iterator of references to elements of a row.
-    fn get_row<'a>(&'a self, i: usize) -> impl Iterator<Item = &'a T>
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = &'a T>
     where
         T: 'a,
     {
@@ -35,16 +54,21 @@ pub trait SymmetricLowerTri<T>: Triangle<T> {
     }
 
     /// Get an iterator of references to elements of a col.
-    fn get_col<'a>(&'a self, i: usize) -> impl Iterator<Item = &'a T>
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = &'a T>
     where
         T: 'a,
     {
-        SymmetricLowerTri::get_col_indices(self, i).map(|el| &self.inner()[el])
+        SymmetricLowerTri::get_col_indices(self, j).map(|el| &self.inner()[el])
     }
 
     /// Get all indices of a row.
-    fn get_row_indices<'a, 'b>(&'a self, i: usize) -> Box<dyn Iterator<Item = usize> + 'b> {
-        debug_assert!(i <= self.n() - 1);
+    fn get_row_indices<'a, 'b>(
+        &'a self,
+        i: impl Into<Row>,
+    ) -> Box<dyn Iterator<Item = usize> + 'b> {
+        let Row(i) = i.into();
+
+        debug_assert!(i < self.n());
 
         if i == 0 {
             Box::new(base::get_col_indices(i, self.n() - 1))
@@ -54,9 +78,253 @@ pub trait SymmetricLowerTri<T>: Triangle<T> {
     }
 
     /// Get all indices of a column.
-    fn get_col_indices<'a, 'b>(&'a self, j: usize) -> impl Iterator<Item = usize> + 'b {
+    fn get_col_indices<'a, 'b>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = usize> + 'b {
+        let Col(j) = j.into();
+
         SymmetricLowerTri::get_row_indices(self, j)
     }
+
+    /// Iterate all stored `(i, j)` indices of the triangle.
+    fn iter_triangle_indices<'b>(&self) -> impl Iterator<Item = (usize, usize)> + 'b {
+        base::iter_triangle_indices(self.n().saturating_sub(1)).map(|(i, j)| (i + 1, j))
+    }
+
+    /// Iterate all `(i, j)` indices and references to elements of the triangle,
+    /// in storage order.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        SymmetricLowerTri::iter_triangle_indices(self)
+            .zip(self.inner().iter())
+            .map(|((i, j), el)| (i, j, el))
+    }
+
+    /// Iterate all `(i, j)` indices and references to elements of the triangle,
+    /// in storage order, yielding each element as both `(i, j)` and `(j, i)`.
+    fn iter_mirrored<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        SymmetricLowerTri::iter(self).flat_map(|(i, j, el)| [(i, j, el), (j, i, el)])
+    }
+
+    /// Get the indices of, and a reference to, the smallest element. Ignores
+    /// incomparable elements such as `NaN`, and returns the first of equal elements.
+    fn argmin<'a>(&'a self) -> Option<(usize, usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SymmetricLowerTri::argmin_by_key(self, |el| el)
+    }
+
+    /// Get the indices of, and a reference to, the largest element. Ignores
+    /// incomparable elements such as `NaN`, and returns the first of equal elements.
+    fn argmax<'a>(&'a self) -> Option<(usize, usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SymmetricLowerTri::argmax_by_key(self, |el| el)
+    }
+
+    /// Get the indices of, and a reference to, the element with the smallest key.
+    /// Ignores incomparable keys such as `NaN`, and returns the first of equal keys.
+    fn argmin_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        select_by_key(
+            SymmetricLowerTri::iter(self),
+            |(_, _, el)| f(el),
+            Ordering::Less,
+        )
+    }
+
+    /// Get the indices of, and a reference to, the element with the largest key.
+    /// Ignores incomparable keys such as `NaN`, and returns the first of equal keys.
+    fn argmax_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        select_by_key(
+            SymmetricLowerTri::iter(self),
+            |(_, _, el)| f(el),
+            Ordering::Greater,
+        )
+    }
+
+    /// Get the column index of, and a reference to, the smallest element of a
+    /// row. Ignores incomparable elements such as `NaN`, and returns the first of
+    /// equal elements.
+    fn row_argmin<'a>(&'a self, i: impl Into<Row>) -> Option<(usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SymmetricLowerTri::row_argmin_by_key(self, i, |el| el)
+    }
+
+    /// Get the column index of, and a reference to, the element of a row with
+    /// the smallest key. Ignores incomparable keys such as `NaN`, and returns the
+    /// first of equal keys.
+    fn row_argmin_by_key<'a, K: PartialOrd>(
+        &'a self,
+        i: impl Into<Row>,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, &'a T)>
+    where
+        T: 'a,
+    {
+        let Row(i) = i.into();
+
+        let row = (0..self.n())
+            .filter(|j| *j != i)
+            .zip(SymmetricLowerTri::get_row(self, i));
+        select_by_key(row, |(_, el)| f(el), Ordering::Less)
+    }
+
+    /// Get the column index of, and a reference to, the smallest element of
+    /// every row. Ignores incomparable elements such as `NaN`.
+    fn nearest_neighbours<'a>(&'a self) -> Vec<Option<(usize, &'a T)>>
+    where
+        T: PartialOrd + 'a,
+    {
+        SymmetricLowerTri::nearest_neighbours_by_key(self, |el| el)
+    }
+
+    /// Get the column index of, and a reference to, the element with the
+    /// smallest key of every row. Ignores incomparable keys such as `NaN`.
+    fn nearest_neighbours_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Vec<Option<(usize, &'a T)>>
+    where
+        T: 'a,
+    {
+        (0..self.n())
+            .map(|i| SymmetricLowerTri::row_argmin_by_key(self, i, &mut f))
+            .collect()
+    }
+
+    /// Get the sum of each row, such as the degree vector of a weighted graph.
+    ///
+    /// Sums all rows in a single pass over the inner collection.
+    fn row_sums(&self) -> Vec<T>
+    where
+        T: Copy + Default + AddAssign,
+    {
+        let mut sums = vec![T::default(); self.n()];
+        for (i, j, el) in SymmetricLowerTri::iter(self) {
+            sums[i] += *el;
+            sums[j] += *el;
+        }
+
+        sums
+    }
+
+    /// Embed the `n` points of a distance matrix in `k` dimensions by classical
+    /// multidimensional scaling.
+    ///
+    /// Double-centres the squared distances and scales the eigenvectors of the
+    /// `k` largest eigenvalues. Returns an [`Error::DimensionMismatch`] if `k`
+    /// exceeds `n`.
+    fn classical_mds(&self, k: usize) -> Result<Mds, Error>
+    where
+        T: ToF64,
+    {
+        let distances = SymmetricLowerTri::iter(self).map(|(i, j, el)| (i, j, el.to_f64()));
+        mds::classical_mds(self.n(), distances, k)
+    }
+
+    /// Get a reference to an element without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `i != j`, `i < n` and `j < n`, and an inner collection of
+    /// `tri_num(n - 1)` elements.
+    unsafe fn get_element_unchecked(&self, i: impl Into<Row>, j: impl Into<Col>) -> &T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let index = if j < i {
+            base::get_element_index(i - 1, j)
+        } else {
+            base::get_element_index(j - 1, i)
+        };
+
+        self.inner().get_unchecked(index)
+    }
+
+    /// Get an iterator of references to elements of a row without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `i < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_row_unchecked<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        SymmetricLowerTri::get_row_indices(self, i)
+            .map(|el| unsafe { self.inner().get_unchecked(el) })
+    }
+
+    /// Get an iterator of references to elements of a col without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `j < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_col_unchecked<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        SymmetricLowerTri::get_col_indices(self, j)
+            .map(|el| unsafe { self.inner().get_unchecked(el) })
+    }
+
+    /// Get a formatter adapter displaying the triangle matrix.
+    fn display(&self) -> TriDisplay<'_, SymmetricLower, Self, T>
+    where
+        Self: Sized,
+    {
+        TriDisplay::new(self)
+    }
+
+    /// Get a view of the triangle matrix with the constant `diagonal`, getting
+    /// full rows and columns of `n` elements.
+    fn with_diagonal(&self, diagonal: T) -> ConstDiagonal<'_, SymmetricLower, Self, T>
+    where
+        Self: Sized,
+        T: Default,
+    {
+        ConstDiagonal::new(self, diagonal)
+    }
+
+    /// Get an undirected graph with the elements as edge weights.
+    fn graph(&self) -> TriangleGraph<'_, SymmetricLower, Self, T>
+    where
+        Self: Sized,
+    {
+        TriangleGraph::new(self)
+    }
+
+    /// Validate the length of the inner collection against the axis length, `n`.
+    fn validate(&self) -> Result<(), Error> {
+        validate_len(self.n(), self.inner().len())
+    }
+
+    /// Validate the triangle, returning it if the inner collection length
+    /// matches the axis length, `n`.
+    fn validated(self) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        SymmetricLowerTri::validate(&self)?;
+        Ok(self)
+    }
 }
 
 impl<T, U: Triangle<T>> SymmetricLowerTri<T> for U {}
@@ -66,9 +334,11 @@ where
     Self::Inner: DerefMut,
 {
     /// Get a mutable reference to an element.
-    fn get_element_mut<'a>(&'a mut self, i: usize, j: usize) -> &'a mut T {
-        debug_assert!(i <= self.n() - 1);
-        debug_assert!(j <= self.n() - 1);
+    fn get_element_mut<'a>(&'a mut self, i: impl Into<Row>, j: impl Into<Col>) -> &'a mut T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
 
         let index = if j < i {
             base::get_element_index(i - 1, j)
@@ -78,6 +348,43 @@ where
 
         &mut self.inner_mut()[index]
     }
+
+    /// Get a mutable reference to the element at a pair of indices.
+    fn get_pair_mut(&mut self, pair: impl Into<Pair>) -> &mut T {
+        let Pair { i, j } = pair.into();
+
+        SymmetricLowerTriMut::get_element_mut(self, i, j)
+    }
+
+    /// Get a mutable reference to an element without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `i != j`, `i < n` and `j < n`, and an inner collection of
+    /// `tri_num(n - 1)` elements.
+    unsafe fn get_element_unchecked_mut(&mut self, i: impl Into<Row>, j: impl Into<Col>) -> &mut T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let index = if j < i {
+            base::get_element_index(i - 1, j)
+        } else {
+            base::get_element_index(j - 1, i)
+        };
+
+        self.inner_mut().get_unchecked_mut(index)
+    }
+
+    /// Iterate all `(i, j)` indices and mutable references to elements of the
+    /// triangle, in storage order.
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        base::iter_triangle_indices(self.n().saturating_sub(1))
+            .map(|(i, j)| (i + 1, j))
+            .zip(self.inner_mut().iter_mut())
+            .map(|((i, j), el)| (i, j, el))
+    }
 }
 
 impl<T, U: Triangle<T> + TriangleMut<T>> SymmetricLowerTriMut<T> for U where
@@ -89,7 +396,7 @@ impl<T, U: Triangle<T> + TriangleMut<T>> SymmetricLowerTriMut<T> for U where
 mod tests {
 
     use super::{SymmetricLowerTri, SymmetricLowerTriMut};
-    use crate::{Triangle, TriangleMut};
+    use crate::{Col, Error, Pair, Row, Triangle, TriangleMut};
 
     struct LoTriVec(usize, Vec<usize>);
 
@@ -238,4 +545,300 @@ mod tests {
         assert_eq!(m.get_col(3).cloned().collect::<Vec<_>>(), [3, 4, 5, 9]);
         assert_eq!(m.get_col(4).cloned().collect::<Vec<_>>(), [6, 7, 8, 9]);
     }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(LoTriVec(5, vec![0; 10]).validate(), Ok(()));
+        assert_eq!(LoTriVec(1, vec![]).validate(), Ok(()));
+        assert_eq!(LoTriVec(0, vec![]).validate(), Err(Error::EmptyAxis));
+        assert_eq!(
+            LoTriVec(4, vec![0; 10]).validate(),
+            Err(Error::InvalidLength {
+                expected: 6,
+                actual: 10
+            })
+        );
+
+        assert!(LoTriVec(5, vec![0; 10]).validated().is_ok());
+        assert!(LoTriVec(5, vec![0; 9]).validated().is_err());
+    }
+
+    #[test]
+    fn test_get_element_unchecked() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        for (i, j) in (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|(i, j)| i != j)
+        {
+            assert_eq!(
+                unsafe { m.get_element_unchecked(i, j) },
+                m.get_element(i, j)
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_element_unchecked_mut() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let mut m = LoTriVec(n, v);
+        let pairs = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|(i, j)| i != j)
+            .collect::<Vec<_>>();
+
+        for (k, &(i, j)) in pairs.iter().enumerate() {
+            unsafe { *m.get_element_unchecked_mut(i, j) = k + 10 };
+            assert_eq!(*m.get_element(i, j), k + 10);
+        }
+    }
+
+    #[test]
+    fn test_get_row_unchecked() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        for i in 0..n {
+            assert!(unsafe { m.get_row_unchecked(i) }.eq(m.get_row(i)));
+        }
+    }
+
+    #[test]
+    fn test_get_col_unchecked() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        for j in 0..n {
+            assert!(unsafe { m.get_col_unchecked(j) }.eq(m.get_col(j)));
+        }
+    }
+
+    #[test]
+    fn test_row_col_indices() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        assert_eq!(*m.get_element(Row(3), Col(1)), 4);
+        assert_eq!(*m.get_element(1, Col(3)), 4);
+        assert_eq!(m.get_row(Row(2)).cloned().collect::<Vec<_>>(), [1, 2, 5, 8]);
+        assert_eq!(m.get_col(Col(2)).cloned().collect::<Vec<_>>(), [1, 2, 5, 8]);
+    }
+
+    #[test]
+    fn test_pair() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let mut m = LoTriVec(n, v);
+
+        assert_eq!(*m.get_pair((3, 1)), 4);
+        assert_eq!(*m.get_pair(Pair::new(1, 3)), 4);
+
+        *m.get_pair_mut(Pair::lower(2, 4)) = 10;
+        assert_eq!(*m.get_element(4, 2), 10);
+    }
+
+    #[test]
+    fn test_iter_triangle_indices() {
+        let n = 5;
+        let m = LoTriVec(n, Vec::new());
+
+        #[rustfmt::skip]
+        assert_eq!(m.iter_triangle_indices().collect::<Vec<_>>(), [
+            (1, 0),
+            (2, 0), (2, 1),
+            (3, 0), (3, 1), (3, 2),
+            (4, 0), (4, 1), (4, 2), (4, 3),
+        ]);
+    }
+
+    #[test]
+    fn test_iter() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        assert_eq!(m.iter().count(), 10);
+        for (k, (i, j, el)) in m.iter().enumerate() {
+            assert_eq!(*el, k);
+            assert_eq!(m.get_element(i, j), el);
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let mut m = LoTriVec(n, v);
+
+        for (i, j, el) in m.iter_mut() {
+            *el = i * 10 + j;
+        }
+        for (i, j) in m.iter_triangle_indices() {
+            assert_eq!(*m.get_element(i, j), i * 10 + j);
+        }
+    }
+
+    #[test]
+    fn test_iter_mirrored() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        assert_eq!(m.iter_mirrored().count(), 20);
+        for (i, j, el) in m.iter_mirrored() {
+            assert_eq!(m.get_element(i, j), el);
+            assert_eq!(m.get_element(j, i), el);
+        }
+    }
+
+    #[test]
+    fn test_argmin_argmax() {
+        #[rustfmt::skip]
+        let v = vec![
+            3,
+            1, 2,
+            3, 0, 6,
+            7, 9, 9, 1,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        assert_eq!(m.argmin(), Some((3, 1, &0)));
+        assert_eq!(m.argmax(), Some((4, 1, &9)));
+        assert_eq!(m.argmin_by_key(|el| el.abs_diff(5)), Some((3, 2, &6)));
+    }
+
+    #[test]
+    fn test_row_argmin() {
+        #[rustfmt::skip]
+        let v = vec![
+            3,
+            1, 2,
+            3, 0, 6,
+            7, 9, 9, 1,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        assert_eq!(m.row_argmin(0), Some((2, &1)));
+        assert_eq!(m.row_argmin(1), Some((3, &0)));
+        assert_eq!(m.row_argmin(2), Some((0, &1)));
+        assert_eq!(m.row_argmin(3), Some((1, &0)));
+        assert_eq!(m.row_argmin(4), Some((3, &1)));
+    }
+
+    #[test]
+    fn test_nearest_neighbours() {
+        #[rustfmt::skip]
+        let v = vec![
+            f64::NAN,
+            1.0, 2.0,
+            3.0, 0.0, 6.0,
+            7.0, 9.0, 9.0, 1.0,
+        ];
+        let m = crate::Tri::<crate::SymmetricLower, _>::new(5, v);
+
+        assert_eq!(
+            m.nearest_neighbours(),
+            [
+                Some((2, &1.0)),
+                Some((3, &0.0)),
+                Some((0, &1.0)),
+                Some((1, &0.0)),
+                Some((3, &1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_row_sums() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+        let n = 5;
+        let m = LoTriVec(n, v);
+
+        assert_eq!(m.row_sums(), [10, 13, 16, 21, 30]);
+        for i in 0..n {
+            assert_eq!(m.row_sums()[i], m.get_row(i).sum::<usize>());
+        }
+    }
+
+    #[test]
+    fn test_classical_mds() {
+        // Points at `0`, `1` and `3` on a line.
+        let m = crate::Tri::<crate::SymmetricLower, _>::new(3, vec![1_u64, 3, 2]);
+        let mds = m.classical_mds(2).unwrap();
+        let x = &mds.embedding;
+
+        assert_eq!(x.len(), 6);
+        assert!(((x[2] - x[0]).abs() - 1.0).abs() < 1e-10);
+        assert!(((x[4] - x[0]).abs() - 3.0).abs() < 1e-10);
+        assert!(x[1].abs() < 1e-6 && x[3].abs() < 1e-6 && x[5].abs() < 1e-6);
+        assert!((mds.explained_variance[0] - 1.0).abs() < 1e-10);
+    }
 }
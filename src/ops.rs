@@ -1,10 +1,115 @@
 //! Triangle matrix operations.
+use std::cmp::Ordering;
+
+use crate::{Error, Layout};
 
 /// Calculate the triangle number for `n`.
 pub fn tri_num(n: usize) -> usize {
     (n * (n + 1)) / 2
 }
 
+/// Validate the length, `len`, of a triangle matrix collection without diagonal
+/// elements and with an axis length of `n`.
+pub fn validate_len(n: usize, len: usize) -> Result<(), Error> {
+    if n == 0 {
+        return Err(Error::EmptyAxis);
+    }
+
+    let expected = tri_num(n - 1);
+    if len != expected {
+        return Err(Error::InvalidLength {
+            expected,
+            actual: len,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate the length, `len`, of a packed triangle matrix collection including
+/// diagonal elements and with an axis length of `n`.
+pub fn validate_packed_len(n: usize, len: usize) -> Result<(), Error> {
+    if n == 0 {
+        return Err(Error::EmptyAxis);
+    }
+
+    let expected = tri_num(n);
+    if len != expected {
+        return Err(Error::InvalidLength {
+            expected,
+            actual: len,
+        });
+    }
+
+    Ok(())
+}
+
+/// Get the element, `el`, of the pair `(i, j)` of a triangle storing elements
+/// in the orientation of the layout, `L`, applying `mirror` where `(i, j)` has
+/// the mirrored orientation.
+pub(crate) fn oriented<L: Layout, T>(i: usize, j: usize, el: T, mirror: impl FnOnce(T) -> T) -> T {
+    if L::contains(i, j) {
+        el
+    } else {
+        mirror(el)
+    }
+}
+
+/// Get the elements of row `i`, of a symmetric triangle with an axis length of
+/// `n`, storing elements in the orientation of the layout, `L`, applying
+/// `mirror` to elements with the mirrored orientation.
+pub(crate) fn oriented_row<'a, L: Layout, T: Copy + 'a>(
+    n: usize,
+    i: usize,
+    row: impl Iterator<Item = &'a T> + 'a,
+    mirror: impl Fn(T) -> T + 'a,
+) -> impl Iterator<Item = T> + 'a {
+    (0..n)
+        .filter(move |j| *j != i)
+        .zip(row)
+        .map(move |(j, el)| oriented::<L, _>(i, j, *el, &mirror))
+}
+
+/// Export the `(i, j)` elements of a triangle with an axis length of `n` as a
+/// row-major `n × n` collection, with `mirror` applied to the element at
+/// `(j, i)`, and `T::default()` diagonal elements.
+pub(crate) fn mirrored_dense<T: Copy + Default>(
+    n: usize,
+    elements: impl Iterator<Item = (usize, usize, T)>,
+    mirror: impl Fn(T) -> T,
+) -> Vec<T> {
+    let mut dense = vec![T::default(); n * n];
+    for (i, j, el) in elements {
+        dense[i * n + j] = el;
+        dense[j * n + i] = mirror(el);
+    }
+
+    dense
+}
+
+/// Select the first item with the key ordered as `order` relative to all other
+/// keys, ignoring incomparable keys such as `NaN`.
+pub(crate) fn select_by_key<X, K: PartialOrd>(
+    iter: impl Iterator<Item = X>,
+    mut f: impl FnMut(&X) -> K,
+    order: Ordering,
+) -> Option<X> {
+    let mut selected: Option<(X, K)> = None;
+    for item in iter {
+        let key = f(&item);
+        if key.partial_cmp(&key).is_none() {
+            continue;
+        }
+
+        match &selected {
+            Some((_, selected_key)) if key.partial_cmp(selected_key) != Some(order) => {}
+            _ => selected = Some((item, key)),
+        }
+    }
+
+    selected.map(|(item, _)| item)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -21,4 +126,62 @@ mod tests {
         assert_eq!(tri_num(4), acc_num(4));
         assert_eq!(tri_num(5), acc_num(5));
     }
+
+    #[test]
+    fn test_validate_len() {
+        assert_eq!(validate_len(0, 0), Err(Error::EmptyAxis));
+        assert_eq!(validate_len(1, 0), Ok(()));
+        assert_eq!(validate_len(5, 10), Ok(()));
+        assert_eq!(
+            validate_len(5, 9),
+            Err(Error::InvalidLength {
+                expected: 10,
+                actual: 9
+            })
+        );
+        assert_eq!(
+            validate_len(4, 10),
+            Err(Error::InvalidLength {
+                expected: 6,
+                actual: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_packed_len() {
+        assert_eq!(validate_packed_len(0, 0), Err(Error::EmptyAxis));
+        assert_eq!(validate_packed_len(1, 1), Ok(()));
+        assert_eq!(validate_packed_len(4, 10), Ok(()));
+        assert_eq!(
+            validate_packed_len(5, 10),
+            Err(Error::InvalidLength {
+                expected: 15,
+                actual: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_by_key() {
+        let v = [3.0, f64::NAN, 1.0, 4.0, 1.0, 4.0];
+        let iter = || v.iter().enumerate();
+
+        assert_eq!(
+            select_by_key(iter(), |(_, el)| **el, Ordering::Less),
+            Some((2, &1.0))
+        );
+        assert_eq!(
+            select_by_key(iter(), |(_, el)| **el, Ordering::Greater),
+            Some((3, &4.0))
+        );
+        assert_eq!(
+            select_by_key(iter().take(2), |(_, el)| -**el, Ordering::Less),
+            Some((0, &3.0))
+        );
+        assert_eq!(
+            select_by_key(iter().skip(1).take(1), |(_, el)| **el, Ordering::Less),
+            None
+        );
+    }
 }
@@ -0,0 +1,377 @@
+//! Pearson, Spearman and Kendall correlation matrices.
+//!
+//! Builds a symmetric upper triangle of the correlation of each pair of `n`
+//! variables from column-major data, where variable `k` is the column
+//! `data[k * m..(k + 1) * m]` of `m` observations. Missing observations are
+//! `NaN`.
+//!
+//! The [`pearson`], [`spearman`] and [`kendall`] functions calculate on the
+//! calling thread. [`Correlation`] additionally allows calculating pairs in
+//! parallel across a number of scoped threads.
+use std::cmp::Ordering;
+use std::thread;
+
+use crate::{upper, Error, SymmetricUpper, Tri};
+
+/// The handling of missing (`NaN`) observations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Missing {
+    /// The correlation of a pair with any missing observation is `NaN`.
+    #[default]
+    Propagate,
+    /// The correlation of a pair uses the observations present in both variables.
+    PairwiseComplete,
+}
+
+/// Get the columns of `n` variables of column-major data.
+fn columns(data: &[f64], n: usize) -> Result<Vec<&[f64]>, Error> {
+    if n == 0 {
+        return Err(Error::EmptyAxis);
+    }
+    if data.len() % n != 0 {
+        return Err(Error::DimensionMismatch {
+            expected: n,
+            actual: data.len(),
+        });
+    }
+
+    let m = data.len() / n;
+    Ok((0..n).map(|k| &data[k * m..(k + 1) * m]).collect())
+}
+
+/// The minimum number of observations across all pairs, `m tri_num(n - 1)`,
+/// calculated in parallel. Smaller matrices are calculated on the calling
+/// thread.
+const PARALLEL_MIN_WORK: usize = 1 << 16;
+
+/// Fill a symmetric upper triangle of `n` variables of `m` observations with
+/// `f(i, j)` for each `i < j` pair.
+///
+/// Each pair is independent, and pairs are calculated in parallel across up to
+/// `threads` scoped threads if the work exceeds [`PARALLEL_MIN_WORK`].
+fn fill_pairwise(
+    n: usize,
+    m: usize,
+    threads: usize,
+    f: impl Fn(usize, usize) -> f64 + Sync,
+) -> Tri<SymmetricUpper, Vec<f64>> {
+    let indices = upper::base::iter_triangle_indices(n.saturating_sub(1))
+        .map(|(i, j)| (i, j + 1))
+        .collect::<Vec<_>>();
+
+    if threads <= 1 || indices.len().saturating_mul(m) < PARALLEL_MIN_WORK {
+        let inner = indices.iter().map(|(i, j)| f(*i, *j)).collect();
+        return Tri::new(n, inner);
+    }
+
+    let mut inner = vec![0.0; indices.len()];
+    let chunk = indices.len().div_ceil(threads);
+    thread::scope(|s| {
+        for (out, indices) in inner.chunks_mut(chunk).zip(indices.chunks(chunk)) {
+            let f = &f;
+            s.spawn(move || {
+                for (el, (i, j)) in out.iter_mut().zip(indices) {
+                    *el = f(*i, *j);
+                }
+            });
+        }
+    });
+
+    Tri::new(n, inner)
+}
+
+/// Get the observation pairs of `x` and `y`, or `None` if a pair with a missing
+/// observation is propagated.
+fn pairs(x: &[f64], y: &[f64], missing: Missing) -> Option<(Vec<f64>, Vec<f64>)> {
+    let complete = |(x, y): &(&f64, &f64)| !x.is_nan() && !y.is_nan();
+    if missing == Missing::Propagate && !x.iter().zip(y.iter()).all(|el| complete(&el)) {
+        return None;
+    }
+
+    Some(x.iter().zip(y.iter()).filter(complete).unzip())
+}
+
+/// Calculate the Pearson correlation of `x` and `y`.
+fn pearson_pair(x: &[f64], y: &[f64]) -> f64 {
+    let len = x.len() as f64;
+    if x.len() < 2 {
+        return f64::NAN;
+    }
+
+    let (mean_x, mean_y) = (x.iter().sum::<f64>() / len, y.iter().sum::<f64>() / len);
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(y.iter()) {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+
+    sxy / (sxx * syy).sqrt()
+}
+
+/// Get the ranks of `x`, from `1`, with the average rank of tied values.
+fn ranks(x: &[f64]) -> Vec<f64> {
+    let mut order = (0..x.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| x[*a].total_cmp(&x[*b]));
+
+    let mut ranks = vec![0.0; x.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let end = start
+            + order[start..]
+                .iter()
+                .take_while(|k| x[**k] == x[order[start]])
+                .count();
+        let rank = (start + end + 1) as f64 / 2.0;
+        order[start..end].iter().for_each(|k| ranks[*k] = rank);
+        start = end;
+    }
+
+    ranks
+}
+
+/// Calculate the Kendall tau-b correlation of `x` and `y`.
+fn kendall_pair(x: &[f64], y: &[f64]) -> f64 {
+    if x.len() < 2 {
+        return f64::NAN;
+    }
+
+    let (mut concordant, mut discordant) = (0i64, 0i64);
+    let (mut tied_x, mut tied_y, mut total) = (0i64, 0i64, 0i64);
+    for a in 0..x.len() {
+        for b in a + 1..x.len() {
+            total += 1;
+            match (x[a].partial_cmp(&x[b]), y[a].partial_cmp(&y[b])) {
+                (Some(Ordering::Equal), Some(Ordering::Equal)) => {
+                    tied_x += 1;
+                    tied_y += 1;
+                }
+                (Some(Ordering::Equal), _) => tied_x += 1,
+                (_, Some(Ordering::Equal)) => tied_y += 1,
+                (dx, dy) if dx == dy => concordant += 1,
+                _ => discordant += 1,
+            }
+        }
+    }
+
+    (concordant - discordant) as f64 / (((total - tied_x) * (total - tied_y)) as f64).sqrt()
+}
+
+/// A builder of correlation matrices.
+///
+/// Calculates on the calling thread by default. Setting `threads` above `1`
+/// calculates pairs of large matrices in parallel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Correlation {
+    missing: Missing,
+    threads: usize,
+}
+
+impl Default for Correlation {
+    fn default() -> Self {
+        Correlation::new()
+    }
+}
+
+impl Correlation {
+    /// Create a correlation matrix builder.
+    pub fn new() -> Self {
+        Correlation {
+            missing: Missing::Propagate,
+            threads: 1,
+        }
+    }
+
+    /// Set the handling of missing observations. Defaults to
+    /// [`Missing::Propagate`].
+    pub fn missing(mut self, missing: Missing) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    /// Set the maximum number of threads. Defaults to `1`, calculating on the
+    /// calling thread.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Build the Pearson correlation matrix of `n` variables of column-major
+    /// data.
+    pub fn pearson(&self, data: &[f64], n: usize) -> Result<Tri<SymmetricUpper, Vec<f64>>, Error> {
+        let columns = columns(data, n)?;
+
+        Ok(fill_pairwise(n, data.len() / n, self.threads, |i, j| {
+            pairs(columns[i], columns[j], self.missing)
+                .map_or(f64::NAN, |(x, y)| pearson_pair(&x, &y))
+        }))
+    }
+
+    /// Build the Spearman rank correlation matrix of `n` variables of
+    /// column-major data. Tied values have the average of their ranks.
+    pub fn spearman(&self, data: &[f64], n: usize) -> Result<Tri<SymmetricUpper, Vec<f64>>, Error> {
+        let columns = columns(data, n)?;
+
+        Ok(fill_pairwise(n, data.len() / n, self.threads, |i, j| {
+            pairs(columns[i], columns[j], self.missing)
+                .map_or(f64::NAN, |(x, y)| pearson_pair(&ranks(&x), &ranks(&y)))
+        }))
+    }
+
+    /// Build the Kendall tau-b rank correlation matrix of `n` variables of
+    /// column-major data. Tied pairs count as neither concordant nor discordant.
+    pub fn kendall(&self, data: &[f64], n: usize) -> Result<Tri<SymmetricUpper, Vec<f64>>, Error> {
+        let columns = columns(data, n)?;
+
+        Ok(fill_pairwise(n, data.len() / n, self.threads, |i, j| {
+            pairs(columns[i], columns[j], self.missing)
+                .map_or(f64::NAN, |(x, y)| kendall_pair(&x, &y))
+        }))
+    }
+}
+
+/// Build the Pearson correlation matrix of `n` variables of column-major data
+/// on the calling thread.
+pub fn pearson(
+    data: &[f64],
+    n: usize,
+    missing: Missing,
+) -> Result<Tri<SymmetricUpper, Vec<f64>>, Error> {
+    Correlation::new().missing(missing).pearson(data, n)
+}
+
+/// Build the Spearman rank correlation matrix of `n` variables of column-major
+/// data on the calling thread. Tied values have the average of their ranks.
+pub fn spearman(
+    data: &[f64],
+    n: usize,
+    missing: Missing,
+) -> Result<Tri<SymmetricUpper, Vec<f64>>, Error> {
+    Correlation::new().missing(missing).spearman(data, n)
+}
+
+/// Build the Kendall tau-b rank correlation matrix of `n` variables of
+/// column-major data on the calling thread. Tied pairs count as neither
+/// concordant nor discordant.
+pub fn kendall(
+    data: &[f64],
+    n: usize,
+    missing: Missing,
+) -> Result<Tri<SymmetricUpper, Vec<f64>>, Error> {
+    Correlation::new().missing(missing).kendall(data, n)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::Triangle;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    // Three variables of five observations, where the third is the square of
+    // the first, and the second has a tie.
+    #[rustfmt::skip]
+    const DATA: [f64; 15] = [
+        1.0, 2.0, 3.0, 4.0, 5.0,
+        2.0, 1.0, 2.0, 5.0, 4.0,
+        1.0, 4.0, 9.0, 16.0, 25.0,
+    ];
+
+    #[test]
+    fn test_ranks() {
+        assert_eq!(ranks(&[3.0, 1.0, 2.0]), [3.0, 1.0, 2.0]);
+        assert_eq!(ranks(&[2.0, 1.0, 2.0, 5.0, 2.0]), [3.0, 1.0, 3.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn test_pearson() {
+        let m = pearson(&DATA, 3, Missing::Propagate).unwrap();
+
+        assert_eq!(m.n(), 3);
+        assert!((m[(0, 1)] - 0.769_800_358_919_501).abs() < 1e-12);
+        assert!((m[(0, 2)] - 0.981_104_910_251_592_9).abs() < 1e-12);
+        assert_eq!(m[(1, 0)], m[(0, 1)]);
+    }
+
+    #[test]
+    fn test_spearman() {
+        let m = spearman(&DATA, 3, Missing::Propagate).unwrap();
+
+        // The second variable has ranks `[2.5, 1, 2.5, 5, 4]`.
+        let expected = pearson_pair(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.5, 1.0, 2.5, 5.0, 4.0]);
+        assert_close(&m.into_inner(), &[expected, 1.0, expected]);
+    }
+
+    #[test]
+    fn test_kendall() {
+        let m = kendall(&DATA, 3, Missing::Propagate).unwrap();
+
+        // 7 concordant, 2 discordant and 1 tied pair of the second variable.
+        let expected = 5.0 / (10.0f64 * 9.0).sqrt();
+        assert_close(&m.into_inner(), &[expected, 1.0, expected]);
+    }
+
+    #[test]
+    fn test_missing() {
+        let mut data = DATA;
+        data[7] = f64::NAN;
+
+        let m = pearson(&data, 3, Missing::Propagate).unwrap();
+        assert!(m[(0, 1)].is_nan());
+        assert!(m[(1, 2)].is_nan());
+        assert!((m[(0, 2)] - 0.981_104_910_251_592_9).abs() < 1e-12);
+
+        let m = kendall(&data, 3, Missing::PairwiseComplete).unwrap();
+        let expected = kendall_pair(&[1.0, 2.0, 4.0, 5.0], &[2.0, 1.0, 5.0, 4.0]);
+        assert!((m[(0, 1)] - expected).abs() < 1e-12);
+        assert!((m[(0, 2)] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_parallel() {
+        // 40 variables of 100 observations exceed the parallel threshold.
+        let (n, m) = (40, 100);
+        let data = (0..n * m)
+            .map(|k| ((k * 7919) % 101) as f64)
+            .collect::<Vec<_>>();
+
+        let parallel = Correlation::new().threads(4).pearson(&data, n).unwrap();
+        assert_eq!(parallel, pearson(&data, n, Missing::Propagate).unwrap());
+
+        let parallel = Correlation::new()
+            .threads(4)
+            .missing(Missing::PairwiseComplete)
+            .spearman(&DATA, 3)
+            .unwrap();
+        assert_eq!(
+            parallel,
+            spearman(&DATA, 3, Missing::PairwiseComplete).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            pearson(&DATA, 4, Missing::Propagate).err(),
+            Some(Error::DimensionMismatch {
+                expected: 4,
+                actual: 15
+            })
+        );
+        assert_eq!(
+            pearson(&DATA, 0, Missing::Propagate).err(),
+            Some(Error::EmptyAxis)
+        );
+        assert_eq!(
+            pearson(&DATA, 1, Missing::Propagate).unwrap().into_inner(),
+            Vec::<f64>::new()
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Streaming covariance and correlation accumulation.
+use crate::ops::tri_num;
+use crate::{upper, Error};
+
+/// A numerically stable streaming accumulator of the covariance of `n`
+/// variables.
+///
+/// Updates the means and an upper packed triangle of co-moments, including the
+/// diagonal, with each observation by Welford's method. Accumulators of
+/// separate observations, such as from different threads, can be merged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CovarianceAccumulator {
+    n: usize,
+    count: usize,
+    mean: Vec<f64>,
+    comoment: Vec<f64>,
+}
+
+impl CovarianceAccumulator {
+    /// Create an accumulator of `n` variables without observations.
+    ///
+    /// Panics if `n` is zero.
+    pub fn new(n: usize) -> Self {
+        match CovarianceAccumulator::try_new(n) {
+            Ok(acc) => acc,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Create an accumulator of `n` variables without observations. Returns an
+    /// [`Error::EmptyAxis`] if `n` is zero.
+    pub fn try_new(n: usize) -> Result<Self, Error> {
+        if n == 0 {
+            return Err(Error::EmptyAxis);
+        }
+
+        Ok(CovarianceAccumulator {
+            n,
+            count: 0,
+            mean: vec![0.0; n],
+            comoment: vec![0.0; tri_num(n)],
+        })
+    }
+
+    /// Get the number of variables, `n`.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Get the number of observations.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the mean of each variable.
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Add an observation, `x`, of each variable.
+    pub fn push(&mut self, x: &[f64]) -> Result<(), Error> {
+        if x.len() != self.n {
+            return Err(Error::DimensionMismatch {
+                expected: self.n,
+                actual: x.len(),
+            });
+        }
+
+        self.count += 1;
+        let weight = (self.count - 1) as f64 / self.count as f64;
+        let delta = x
+            .iter()
+            .zip(self.mean.iter())
+            .map(|(x, mean)| x - mean)
+            .collect::<Vec<_>>();
+
+        self.mean
+            .iter_mut()
+            .zip(delta.iter())
+            .for_each(|(mean, delta)| *mean += delta / self.count as f64);
+        self.update(&delta, weight);
+
+        Ok(())
+    }
+
+    /// Merge the observations of `other` into this accumulator.
+    pub fn merge(&mut self, other: &CovarianceAccumulator) -> Result<(), Error> {
+        if other.n != self.n {
+            return Err(Error::AxisMismatch {
+                expected: self.n,
+                actual: other.n,
+            });
+        }
+        if other.count == 0 {
+            return Ok(());
+        }
+
+        let count = self.count + other.count;
+        let (a, b) = (self.count as f64, other.count as f64);
+        let delta = other
+            .mean
+            .iter()
+            .zip(self.mean.iter())
+            .map(|(b, a)| b - a)
+            .collect::<Vec<_>>();
+
+        self.mean
+            .iter_mut()
+            .zip(delta.iter())
+            .for_each(|(mean, delta)| *mean += delta * b / count as f64);
+        self.comoment
+            .iter_mut()
+            .zip(other.comoment.iter())
+            .for_each(|(a, b)| *a += b);
+        self.update(&delta, a * b / count as f64);
+        self.count = count;
+
+        Ok(())
+    }
+
+    /// Add `weight δ δᵀ` to the co-moments.
+    fn update(&mut self, delta: &[f64], weight: f64) {
+        for i in 0..self.n {
+            let row =
+                &mut self.comoment[upper::base::get_row_start_index(i, self.n)..][..self.n - i];
+            let di = weight * delta[i];
+
+            row.iter_mut()
+                .zip(delta[i..].iter())
+                .for_each(|(el, dj)| *el += di * dj);
+        }
+    }
+
+    /// Get the sample covariance as an upper packed triangle, including the
+    /// diagonal, or `None` with fewer than two observations.
+    pub fn covariance(&self) -> Option<Vec<f64>> {
+        if self.count < 2 {
+            return None;
+        }
+
+        let scale = 1.0 / (self.count - 1) as f64;
+        Some(self.comoment.iter().map(|el| el * scale).collect())
+    }
+
+    /// Get the Pearson correlation as an upper packed triangle, including the
+    /// diagonal, or `None` with fewer than two observations.
+    ///
+    /// Correlations of a variable with zero variance, including its diagonal
+    /// element, are `NaN`.
+    pub fn correlation(&self) -> Option<Vec<f64>> {
+        if self.count < 2 {
+            return None;
+        }
+
+        let std = (0..self.n)
+            .map(|i| self.comoment[upper::base::get_row_start_index(i, self.n)].sqrt())
+            .collect::<Vec<_>>();
+        let correlation = upper::base::iter_triangle_indices(self.n)
+            .zip(self.comoment.iter())
+            .map(|((i, j), el)| match i == j {
+                true if *el == 0.0 => f64::NAN,
+                true => 1.0,
+                false => el / (std[i] * std[j]),
+            })
+            .collect();
+
+        Some(correlation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {:?}", a, b);
+        }
+    }
+
+    // Observations of three variables, where the third is `-2` times the first.
+    const OBSERVATIONS: [[f64; 3]; 4] = [
+        [1.0, 2.0, -2.0],
+        [2.0, 1.0, -4.0],
+        [3.0, 4.0, -6.0],
+        [4.0, 3.0, -8.0],
+    ];
+
+    #[rustfmt::skip]
+    const COVARIANCE: [f64; 6] = [
+        5.0 / 3.0, 1.0,       -10.0 / 3.0,
+                   5.0 / 3.0, -2.0,
+                              20.0 / 3.0,
+    ];
+
+    #[test]
+    fn test_covariance() {
+        let mut acc = CovarianceAccumulator::new(3);
+        assert_eq!(acc.covariance(), None);
+
+        for x in OBSERVATIONS.iter() {
+            acc.push(x).unwrap();
+        }
+
+        assert_eq!(acc.count(), 4);
+        assert_close(acc.mean(), &[2.5, 2.5, -5.0]);
+        assert_close(&acc.covariance().unwrap(), &COVARIANCE);
+    }
+
+    #[test]
+    fn test_correlation() {
+        let mut acc = CovarianceAccumulator::new(3);
+        for x in OBSERVATIONS.iter() {
+            acc.push(x).unwrap();
+        }
+
+        #[rustfmt::skip]
+        assert_close(&acc.correlation().unwrap(), &[
+            1.0, 0.6, -1.0,
+                 1.0, -0.6,
+                       1.0,
+        ]);
+
+        let mut acc = CovarianceAccumulator::new(2);
+        acc.push(&[1.0, 1.0]).unwrap();
+        acc.push(&[2.0, 1.0]).unwrap();
+
+        let correlation = acc.correlation().unwrap();
+        assert_eq!(correlation[0], 1.0);
+        assert!(correlation[1].is_nan());
+        assert!(correlation[2].is_nan());
+    }
+
+    #[test]
+    fn test_merge() {
+        let (mut a, mut b) = (CovarianceAccumulator::new(3), CovarianceAccumulator::new(3));
+        a.push(&OBSERVATIONS[0]).unwrap();
+        for x in OBSERVATIONS[1..].iter() {
+            b.push(x).unwrap();
+        }
+
+        a.merge(&b).unwrap();
+        assert_eq!(a.count(), 4);
+        assert_close(a.mean(), &[2.5, 2.5, -5.0]);
+        assert_close(&a.covariance().unwrap(), &COVARIANCE);
+
+        a.merge(&CovarianceAccumulator::new(3)).unwrap();
+        assert_close(&a.covariance().unwrap(), &COVARIANCE);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(CovarianceAccumulator::try_new(0), Err(Error::EmptyAxis));
+
+        let mut acc = CovarianceAccumulator::try_new(3).unwrap();
+
+        assert_eq!(
+            acc.push(&[1.0, 2.0]),
+            Err(Error::DimensionMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            acc.merge(&CovarianceAccumulator::new(2)),
+            Err(Error::AxisMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "axis length `n` is zero")]
+    fn test_new_empty() {
+        CovarianceAccumulator::new(0);
+    }
+}
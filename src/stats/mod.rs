@@ -0,0 +1,7 @@
+//! Triangle matrix statistics.
+pub mod correlation;
+mod covariance;
+mod summary;
+
+pub use covariance::CovarianceAccumulator;
+pub use summary::{ToF64, TriangleStats};
@@ -0,0 +1,246 @@
+//! Summary statistics of triangle matrix elements.
+use std::cmp::Ordering;
+use std::iter::Sum;
+
+use crate::ops::select_by_key;
+use crate::Triangle;
+
+/// An element convertible to `f64` for statistics, rounding to the nearest
+/// representable value where the conversion is lossy.
+pub trait ToF64 {
+    /// Convert the element to `f64`.
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($t:ty),*) => {
+        $(
+            impl ToF64 for $t {
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Summary statistics of the stored elements of a triangle matrix.
+///
+/// Statistics are calculated over the inner collection, counting each stored
+/// element once regardless of the layout. Methods are prefixed with `element_`
+/// where the name would otherwise clash with [`Iterator`] or [`Ord`].
+pub trait TriangleStats<T>: Triangle<T> {
+    /// Get the sum of all elements.
+    fn element_sum(&self) -> T
+    where
+        T: Copy + Sum<T>,
+    {
+        self.inner().iter().copied().sum()
+    }
+
+    /// Get the arithmetic mean of all elements, or `None` if there are no elements.
+    fn mean(&self) -> Option<f64>
+    where
+        T: ToF64,
+    {
+        let len = self.inner().len();
+        if len == 0 {
+            return None;
+        }
+
+        Some(self.inner().iter().map(ToF64::to_f64).sum::<f64>() / len as f64)
+    }
+
+    /// Get a reference to the smallest element. Ignores incomparable elements
+    /// such as `NaN`.
+    fn element_min(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        select_by_key(self.inner().iter(), |el| *el, Ordering::Less)
+    }
+
+    /// Get a reference to the largest element. Ignores incomparable elements
+    /// such as `NaN`.
+    fn element_max(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        select_by_key(self.inner().iter(), |el| *el, Ordering::Greater)
+    }
+
+    /// Get the population variance of all elements, or `None` if there are no
+    /// elements.
+    fn variance(&self) -> Option<f64>
+    where
+        T: ToF64,
+    {
+        let mean = TriangleStats::mean(self)?;
+        let sum_sq = self
+            .inner()
+            .iter()
+            .map(|el| (el.to_f64() - mean).powi(2))
+            .sum::<f64>();
+
+        Some(sum_sq / self.inner().len() as f64)
+    }
+
+    /// Get the `q` quantile of all elements, linearly interpolating between
+    /// the closest ranks. Ignores `NaN` elements, returning `None` if there are
+    /// no other elements.
+    ///
+    /// Panics if `q` is outside of `0.0..=1.0`.
+    fn quantile(&self, q: f64) -> Option<f64>
+    where
+        T: ToF64,
+    {
+        assert!((0.0..=1.0).contains(&q));
+
+        let mut sorted = self
+            .inner()
+            .iter()
+            .map(ToF64::to_f64)
+            .filter(|el: &f64| !el.is_nan())
+            .collect::<Vec<_>>();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let rank = q * (sorted.len() - 1) as f64;
+        let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+
+        Some(sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64))
+    }
+
+    /// Count the elements in each of `bins` equal width bins spanning `min` to
+    /// `max`. The last bin includes `max`, and elements outside of the range,
+    /// or `NaN`, are not counted.
+    ///
+    /// Panics if `bins` is zero, or `max` is not greater than `min`.
+    fn histogram(&self, bins: usize, min: f64, max: f64) -> Vec<usize>
+    where
+        T: ToF64,
+    {
+        assert!(bins != 0);
+        assert!(min < max);
+
+        let width = (max - min) / bins as f64;
+        let mut counts = vec![0; bins];
+        for el in self.inner().iter().map(ToF64::to_f64) {
+            if (min..=max).contains(&el) {
+                let bin = (((el - min) / width) as usize).min(bins - 1);
+                counts[bin] += 1;
+            }
+        }
+
+        counts
+    }
+}
+
+impl<T, U: Triangle<T>> TriangleStats<T> for U {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::TriangleStats;
+    use crate::{SimpleUpper, Tri};
+
+    #[test]
+    fn test_element_sum() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m.element_sum(), 45);
+    }
+
+    #[test]
+    fn test_mean() {
+        #[rustfmt::skip]
+        let v: Vec<usize> = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m.mean(), Some(4.5));
+        assert_eq!(Tri::<SimpleUpper, Vec<u64>>::new(1, vec![]).mean(), None);
+    }
+
+    #[test]
+    fn test_element_min_max() {
+        let m = Tri::<SimpleUpper, _>::new(3, vec![f64::NAN, -1.0, 2.0]);
+
+        assert_eq!(m.element_min(), Some(&-1.0));
+        assert_eq!(m.element_max(), Some(&2.0));
+    }
+
+    #[test]
+    fn test_variance() {
+        #[rustfmt::skip]
+        let v: Vec<i64> = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m.variance(), Some(8.25));
+        assert_eq!(
+            Tri::<SimpleUpper, Vec<u64>>::new(1, vec![]).variance(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        #[rustfmt::skip]
+        let v: Vec<u64> = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m.quantile(0.0), Some(0.0));
+        assert_eq!(m.quantile(0.5), Some(4.5));
+        assert_eq!(m.quantile(0.25), Some(2.25));
+        assert_eq!(m.quantile(1.0), Some(9.0));
+
+        let m = Tri::<SimpleUpper, _>::new(3, vec![f64::NAN, 3.0, 1.0]);
+
+        assert_eq!(m.quantile(0.5), Some(2.0));
+    }
+
+    #[test]
+    fn test_histogram() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m.histogram(3, 0.0, 9.0), [3, 3, 4]);
+        assert_eq!(m.histogram(2, 2.0, 6.0), [2, 3]);
+    }
+}
@@ -0,0 +1,632 @@
+//! A generic triangle matrix collection.
+use std::marker::PhantomData;
+use std::ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::display::TriDisplay;
+use crate::ops::validate_len;
+use crate::{Error, Pair, Triangle, TriangleMut};
+use crate::{SimpleLowerTri, SimpleLowerTriMut, SimpleUpperTri, SimpleUpperTriMut};
+use crate::{SymmetricLowerTri, SymmetricLowerTriMut, SymmetricUpperTri, SymmetricUpperTriMut};
+
+/// A triangle matrix layout.
+///
+/// Dispatches element access to the triangle matrix type of the layout.
+pub trait Layout {
+    /// Whether the indices `i` and `j` lie within the layout.
+    fn contains(i: usize, j: usize) -> bool;
+
+    /// Get a reference to an element.
+    fn get_element<T, M: Triangle<T>>(m: &M, i: usize, j: usize) -> &T;
+
+    /// Get a mutable reference to an element.
+    fn get_element_mut<T, M: TriangleMut<T>>(m: &mut M, i: usize, j: usize) -> &mut T
+    where
+        M::Inner: DerefMut<Target = [T]>;
+}
+
+/// The [`SimpleUpperTri`] layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SimpleUpper;
+
+/// The [`SymmetricUpperTri`] layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SymmetricUpper;
+
+/// The [`SimpleLowerTri`] layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SimpleLower;
+
+/// The [`SymmetricLowerTri`] layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SymmetricLower;
+
+impl Layout for SimpleUpper {
+    fn contains(i: usize, j: usize) -> bool {
+        i < j
+    }
+
+    fn get_element<T, M: Triangle<T>>(m: &M, i: usize, j: usize) -> &T {
+        SimpleUpperTri::get_element(m, i, j)
+    }
+
+    fn get_element_mut<T, M: TriangleMut<T>>(m: &mut M, i: usize, j: usize) -> &mut T
+    where
+        M::Inner: DerefMut<Target = [T]>,
+    {
+        SimpleUpperTriMut::get_element_mut(m, i, j)
+    }
+}
+
+impl Layout for SymmetricUpper {
+    fn contains(i: usize, j: usize) -> bool {
+        i != j
+    }
+
+    fn get_element<T, M: Triangle<T>>(m: &M, i: usize, j: usize) -> &T {
+        SymmetricUpperTri::get_element(m, i, j)
+    }
+
+    fn get_element_mut<T, M: TriangleMut<T>>(m: &mut M, i: usize, j: usize) -> &mut T
+    where
+        M::Inner: DerefMut<Target = [T]>,
+    {
+        SymmetricUpperTriMut::get_element_mut(m, i, j)
+    }
+}
+
+impl Layout for SimpleLower {
+    fn contains(i: usize, j: usize) -> bool {
+        j < i
+    }
+
+    fn get_element<T, M: Triangle<T>>(m: &M, i: usize, j: usize) -> &T {
+        SimpleLowerTri::get_element(m, i, j)
+    }
+
+    fn get_element_mut<T, M: TriangleMut<T>>(m: &mut M, i: usize, j: usize) -> &mut T
+    where
+        M::Inner: DerefMut<Target = [T]>,
+    {
+        SimpleLowerTriMut::get_element_mut(m, i, j)
+    }
+}
+
+impl Layout for SymmetricLower {
+    fn contains(i: usize, j: usize) -> bool {
+        i != j
+    }
+
+    fn get_element<T, M: Triangle<T>>(m: &M, i: usize, j: usize) -> &T {
+        SymmetricLowerTri::get_element(m, i, j)
+    }
+
+    fn get_element_mut<T, M: TriangleMut<T>>(m: &mut M, i: usize, j: usize) -> &mut T
+    where
+        M::Inner: DerefMut<Target = [T]>,
+    {
+        SymmetricLowerTriMut::get_element_mut(m, i, j)
+    }
+}
+
+/// A triangle matrix collection with the layout, `L`, over the collection, `S`.
+///
+/// Implements [`Triangle`] and [`TriangleMut`], and allows indexing elements
+/// with `(i, j)` tuples according to the layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tri<L, S> {
+    n: usize,
+    inner: S,
+    layout: PhantomData<L>,
+}
+
+impl<L, S> Tri<L, S> {
+    /// Create a triangle matrix with an axis length of `n` over `inner`.
+    pub fn new(n: usize, inner: S) -> Self {
+        Tri {
+            n,
+            inner,
+            layout: PhantomData,
+        }
+    }
+
+    /// Create a triangle matrix with an axis length of `n` over `inner`,
+    /// validating the length of `inner`.
+    pub fn try_new<T>(n: usize, inner: S) -> Result<Self, Error>
+    where
+        S: Deref<Target = [T]>,
+    {
+        validate_len(n, inner.len())?;
+        Ok(Tri::new(n, inner))
+    }
+
+    /// Get a formatter adapter displaying the triangle matrix with the layout, `L`.
+    pub fn display<T>(&self) -> TriDisplay<'_, L, Self, T>
+    where
+        L: Layout,
+        S: Deref<Target = [T]>,
+    {
+        TriDisplay::new(self)
+    }
+
+    /// Take the inner collection.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Create a triangle matrix of the same layout by applying `f` to each
+    /// element.
+    pub fn map<T, U>(&self, f: impl FnMut(&T) -> U) -> Tri<L, Vec<U>>
+    where
+        S: Deref<Target = [T]>,
+    {
+        Tri::new(self.n, self.inner.iter().map(f).collect())
+    }
+
+    /// Create a triangle matrix of the same layout by applying `f` to each pair
+    /// of elements of `self` and `other`. Returns an [`Error::InvalidLength`] if
+    /// either inner collection does not match its axis length, and an
+    /// [`Error::AxisMismatch`] if the axis lengths differ.
+    pub fn zip_with<T, U, V, S2>(
+        &self,
+        other: &Tri<L, S2>,
+        mut f: impl FnMut(&T, &U) -> V,
+    ) -> Result<Tri<L, Vec<V>>, Error>
+    where
+        S: Deref<Target = [T]>,
+        S2: Deref<Target = [U]>,
+    {
+        self.validate_operands(other)?;
+
+        let inner = self
+            .inner
+            .iter()
+            .zip(other.inner.iter())
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Ok(Tri::new(self.n, inner))
+    }
+
+    /// Validate the operands of an element-wise operation.
+    fn validate_operands<T, U, S2>(&self, other: &Tri<L, S2>) -> Result<(), Error>
+    where
+        S: Deref<Target = [T]>,
+        S2: Deref<Target = [U]>,
+    {
+        validate_len(self.n, self.inner.len())?;
+        validate_len(other.n, other.inner.len())?;
+
+        if self.n != other.n {
+            return Err(Error::AxisMismatch {
+                expected: self.n,
+                actual: other.n,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Apply `f` to each element in place.
+    pub fn apply<T>(&mut self, f: impl FnMut(&mut T))
+    where
+        S: DerefMut<Target = [T]>,
+    {
+        self.inner.iter_mut().for_each(f);
+    }
+}
+
+impl<L, T, S: Deref<Target = [T]>> Triangle<T> for Tri<L, S> {
+    type Inner = S;
+
+    fn n(&self) -> usize {
+        self.n
+    }
+
+    fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<L, T, S: DerefMut<Target = [T]>> TriangleMut<T> for Tri<L, S> {
+    fn inner_mut(&mut self) -> &mut S {
+        &mut self.inner
+    }
+}
+
+impl<L: Layout, T, S: Deref<Target = [T]>> Index<(usize, usize)> for Tri<L, S> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        L::get_element(self, i, j)
+    }
+}
+
+impl<L: Layout, T, S: DerefMut<Target = [T]>> IndexMut<(usize, usize)> for Tri<L, S> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        L::get_element_mut(self, i, j)
+    }
+}
+
+impl<L: Layout, T, S: Deref<Target = [T]>> Index<Pair> for Tri<L, S> {
+    type Output = T;
+
+    fn index(&self, pair: Pair) -> &T {
+        L::get_element(self, pair.i.0, pair.j.0)
+    }
+}
+
+impl<L: Layout, T, S: DerefMut<Target = [T]>> IndexMut<Pair> for Tri<L, S> {
+    fn index_mut(&mut self, pair: Pair) -> &mut T {
+        L::get_element_mut(self, pair.i.0, pair.j.0)
+    }
+}
+
+/// Element-wise addition. Panics if the axis lengths differ, or if the
+/// length of either inner collection does not match its axis length.
+impl<L, T: Copy + Add<Output = T>, S: Deref<Target = [T]>> Add<&Tri<L, S>> for Tri<L, Vec<T>> {
+    type Output = Tri<L, Vec<T>>;
+
+    fn add(mut self, rhs: &Tri<L, S>) -> Self::Output {
+        if let Err(err) = self.validate_operands(rhs) {
+            panic!("invalid operands: {}", err);
+        }
+
+        self.inner
+            .iter_mut()
+            .zip(rhs.inner.iter())
+            .for_each(|(a, b)| *a = *a + *b);
+        self
+    }
+}
+
+/// Element-wise addition. Panics if the axis lengths differ, or if the
+/// length of either inner collection does not match its axis length.
+impl<L, T: Copy + Add<Output = T>> Add for Tri<L, Vec<T>> {
+    type Output = Tri<L, Vec<T>>;
+
+    fn add(self, rhs: Tri<L, Vec<T>>) -> Self::Output {
+        self + &rhs
+    }
+}
+
+/// Element-wise subtraction. Panics if the axis lengths differ, or if the
+/// length of either inner collection does not match its axis length.
+impl<L, T: Copy + Sub<Output = T>, S: Deref<Target = [T]>> Sub<&Tri<L, S>> for Tri<L, Vec<T>> {
+    type Output = Tri<L, Vec<T>>;
+
+    fn sub(mut self, rhs: &Tri<L, S>) -> Self::Output {
+        if let Err(err) = self.validate_operands(rhs) {
+            panic!("invalid operands: {}", err);
+        }
+
+        self.inner
+            .iter_mut()
+            .zip(rhs.inner.iter())
+            .for_each(|(a, b)| *a = *a - *b);
+        self
+    }
+}
+
+/// Element-wise subtraction. Panics if the axis lengths differ, or if the
+/// length of either inner collection does not match its axis length.
+impl<L, T: Copy + Sub<Output = T>> Sub for Tri<L, Vec<T>> {
+    type Output = Tri<L, Vec<T>>;
+
+    fn sub(self, rhs: Tri<L, Vec<T>>) -> Self::Output {
+        self - &rhs
+    }
+}
+
+/// Scalar multiplication.
+impl<L, T: Copy + Mul<Output = T>> Mul<T> for Tri<L, Vec<T>> {
+    type Output = Tri<L, Vec<T>>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.apply(|el| *el = *el * rhs);
+        self
+    }
+}
+
+/// Element-wise negation.
+impl<L, T: Copy + Neg<Output = T>> Neg for Tri<L, Vec<T>> {
+    type Output = Tri<L, Vec<T>>;
+
+    fn neg(mut self) -> Self::Output {
+        self.apply(|el| *el = -*el);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{Col, Row};
+
+    #[test]
+    fn test_try_new() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        assert!(Tri::<SimpleUpper, _>::try_new(5, v.clone()).is_ok());
+        assert_eq!(
+            Tri::<SimpleUpper, _>::try_new(4, v),
+            Err(Error::InvalidLength {
+                expected: 6,
+                actual: 10
+            })
+        );
+        assert_eq!(
+            Tri::<SimpleUpper, Vec<usize>>::try_new(0, Vec::new()),
+            Err(Error::EmptyAxis)
+        );
+    }
+
+    #[test]
+    fn test_index_simple_upper() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m[(0, 1)], 0);
+        assert_eq!(m[(0, 4)], 3);
+        assert_eq!(m[(1, 3)], 5);
+        assert_eq!(m[(3, 4)], 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_simple_upper_outside() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        let _ = m[(3, 1)];
+    }
+
+    #[test]
+    fn test_index_symmetric_upper() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SymmetricUpper, _>::new(5, v);
+
+        assert_eq!(m[(0, 1)], 0);
+        assert_eq!(m[(1, 0)], 0);
+        assert_eq!(m[(1, 3)], 5);
+        assert_eq!(m[(3, 1)], 5);
+        assert_eq!(m[(4, 3)], 9);
+    }
+
+    #[test]
+    fn test_index_simple_lower() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+
+        let m = Tri::<SimpleLower, _>::new(5, v);
+
+        assert_eq!(m[(1, 0)], 0);
+        assert_eq!(m[(2, 1)], 2);
+        assert_eq!(m[(3, 1)], 4);
+        assert_eq!(m[(4, 3)], 9);
+    }
+
+    #[test]
+    fn test_index_symmetric_lower() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+
+        let m = Tri::<SymmetricLower, _>::new(5, v);
+
+        assert_eq!(m[(1, 0)], 0);
+        assert_eq!(m[(0, 1)], 0);
+        assert_eq!(m[(3, 1)], 4);
+        assert_eq!(m[(1, 3)], 4);
+        assert_eq!(m[(3, 4)], 9);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let mut m = Tri::<SymmetricUpper, _>::new(5, v.clone());
+        m[(3, 1)] = 10;
+        m[(2, 3)] = 11;
+
+        assert_eq!(m[(1, 3)], 10);
+        assert_eq!(m[(3, 2)], 11);
+
+        let mut m = Tri::<SimpleLower, _>::new(5, v);
+        m[(3, 1)] = 10;
+
+        assert_eq!(m[(3, 1)], 10);
+        assert_eq!(m.into_inner()[4], 10);
+    }
+
+    #[test]
+    fn test_slice_inner() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SimpleUpper, &[usize]>::new(5, &v);
+
+        assert_eq!(m[(1, 3)], 5);
+        assert_eq!(
+            SimpleUpperTri::get_row(&m, 1).cloned().collect::<Vec<_>>(),
+            [4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn test_index_pair() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let mut m = Tri::<SymmetricLower, _>::new(5, v.clone());
+
+        assert_eq!(m[Pair::new(3, 1)], 4);
+        assert_eq!(m[Pair::lower(1, 3)], 4);
+
+        m[Pair::lower(2, 4)] = 10;
+        assert_eq!(m[(4, 2)], 10);
+
+        let m = Tri::<SimpleUpper, _>::new(5, v);
+
+        assert_eq!(m[Pair::upper(3, 1)], 5);
+        assert_eq!(m[Pair::new(Row(1), Col(3))], 5);
+    }
+
+    #[test]
+    fn test_map() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let m = Tri::<SymmetricUpper, _>::new(5, v);
+        let m = m.map(|el| *el as f64 / 2.0);
+
+        assert_eq!(m[(3, 1)], 2.5);
+        assert_eq!(m[(4, 3)], 4.5);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        #[rustfmt::skip]
+        let v = vec![
+            0, 1, 2, 3,
+               4, 5, 6,
+                  7, 8,
+                     9,
+        ];
+
+        let a = Tri::<SymmetricUpper, _>::new(5, v);
+        let b = a.map(|el| *el as f64);
+        let m = a.zip_with(&b, |a, b| *a as f64 * 0.25 + b * 0.75).unwrap();
+
+        assert_eq!(m[(3, 1)], 5.0);
+
+        let c = Tri::<SymmetricUpper, _>::new(4, vec![0.0; 6]);
+        assert_eq!(
+            b.zip_with(&c, |a, b| a + b),
+            Err(Error::AxisMismatch {
+                expected: 5,
+                actual: 4
+            })
+        );
+
+        let d = Tri::<SymmetricUpper, _>::new(5, vec![0.0; 9]);
+        assert_eq!(
+            b.zip_with(&d, |a, b| a + b),
+            Err(Error::InvalidLength {
+                expected: 10,
+                actual: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+
+        let mut m = Tri::<SimpleLower, _>::new(5, v);
+        m.apply(|el| *el *= 2);
+
+        assert_eq!(m[(4, 3)], 18);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        #[rustfmt::skip]
+        let v = vec![
+            0,
+            1, 2,
+            3, 4, 5,
+            6, 7, 8, 9,
+        ];
+
+        let a = Tri::<SymmetricLower, _>::new(5, v).map(|el| *el as i64);
+        let b = Tri::<SymmetricLower, _>::new(5, vec![1; 10]);
+
+        assert_eq!((a.clone() + &b)[(4, 3)], 10);
+        assert_eq!((a.clone() + b.clone())[(1, 0)], 1);
+        assert_eq!((a.clone() - &b)[(1, 0)], -1);
+        assert_eq!((a.clone() - b.clone())[(4, 3)], 8);
+        assert_eq!((a.clone() * 3)[(4, 3)], 27);
+        assert_eq!((-a)[(3, 4)], -9);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid operands: axis length `n` is 4, expected 5")]
+    fn test_arithmetic_mismatch() {
+        let a = Tri::<SymmetricLower, _>::new(5, vec![1; 10]);
+        let b = Tri::<SymmetricLower, _>::new(4, vec![1; 6]);
+
+        let _ = a + b;
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid operands: inner collection has 9 elements, expected 10")]
+    fn test_arithmetic_invalid_length() {
+        let a = Tri::<SymmetricLower, _>::new(5, vec![1; 10]);
+        let b = Tri::<SymmetricLower, _>::new(5, vec![1; 9]);
+
+        let _ = a - &b;
+    }
+}
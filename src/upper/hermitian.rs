@@ -0,0 +1,148 @@
+//! A Hermitian upper triangle matrix abstraction.
+use std::ops::DerefMut;
+
+use super::symmetric::{SymmetricUpperTri, SymmetricUpperTriMut};
+use crate::ops::{oriented, oriented_row};
+use crate::{Col, Conjugate, Row, SimpleUpper, Triangle, TriangleMut};
+
+/// A Hermitian upper triangle collection.
+///
+/// Contains `tri_num(n)` elements with `n - 1` rows and columns to account for
+/// the diagonal.
+///
+/// For all indices `i` and `j` where `i != j`, the element `(j, i)` is the
+/// complex conjugate of the element `(i, j)`. Elements are stored in the
+/// orientation `i < j`, and returned by value. Indexing, iteration and
+/// validation of the stored elements are provided by [`SymmetricUpperTri`].
+///
+/// The diagonal, which is real for a Hermitian matrix, is neither stored nor
+/// checked, and must be kept separately where required.
+pub trait HermitianUpperTri<T>: SymmetricUpperTri<T> {
+    /// Get an element, conjugated where `j < i`.
+    fn get_element(&self, i: impl Into<Row>, j: impl Into<Col>) -> T
+    where
+        T: Copy + Conjugate,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let el = *SymmetricUpperTri::get_element(self, i, j);
+        oriented::<SimpleUpper, _>(i, j, el, |el| el.conj())
+    }
+
+    /// Get an iterator of the elements of a row, conjugated where `j < i`.
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Conjugate + 'a,
+    {
+        let Row(i) = i.into();
+
+        let row = SymmetricUpperTri::get_row(self, i);
+        oriented_row::<SimpleUpper, _>(self.n(), i, row, |el| el.conj())
+    }
+
+    /// Get an iterator of the elements of a column, conjugated where `j < i`.
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Conjugate + 'a,
+    {
+        let Col(j) = j.into();
+
+        HermitianUpperTri::get_row(self, j).map(|el| el.conj())
+    }
+}
+
+impl<T, U: Triangle<T>> HermitianUpperTri<T> for U {}
+
+pub trait HermitianUpperTriMut<T>: SymmetricUpperTriMut<T>
+where
+    Self::Inner: DerefMut<Target = [T]>,
+{
+    /// Set an element, storing the conjugate of `value` where `j < i`.
+    fn set_element(&mut self, i: impl Into<Row>, j: impl Into<Col>, value: T)
+    where
+        T: Conjugate,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        *SymmetricUpperTriMut::get_element_mut(self, i, j) =
+            oriented::<SimpleUpper, _>(i, j, value, |el| el.conj());
+    }
+}
+
+impl<T, U: Triangle<T> + TriangleMut<T>> HermitianUpperTriMut<T> for U where
+    Self::Inner: DerefMut<Target = [T]>
+{
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{HermitianUpperTri, HermitianUpperTriMut};
+    use crate::{Complex, Triangle, TriangleMut};
+
+    struct UpTriVec(usize, Vec<Complex<i32>>);
+
+    impl Triangle<Complex<i32>> for UpTriVec {
+        type Inner = Vec<Complex<i32>>;
+
+        fn n(&self) -> usize {
+            self.0
+        }
+
+        fn inner(&self) -> &Vec<Complex<i32>> {
+            &self.1
+        }
+    }
+
+    impl TriangleMut<Complex<i32>> for UpTriVec {
+        fn inner_mut(&mut self) -> &mut Vec<Complex<i32>> {
+            &mut self.1
+        }
+    }
+
+    fn c(re: i32, im: i32) -> Complex<i32> {
+        Complex::new(re, im)
+    }
+
+    #[test]
+    fn test_get_element() {
+        let m = UpTriVec(3, vec![c(1, 1), c(2, -2), c(3, 3)]);
+
+        assert_eq!(m.get_element(0, 1), c(1, 1));
+        assert_eq!(m.get_element(1, 0), c(1, -1));
+        assert_eq!(m.get_element(0, 2), c(2, -2));
+        assert_eq!(m.get_element(2, 0), c(2, 2));
+        assert_eq!(m.get_element(2, 1), c(3, -3));
+    }
+
+    #[test]
+    fn test_get_row_col() {
+        let m = UpTriVec(3, vec![c(1, 1), c(2, -2), c(3, 3)]);
+
+        assert_eq!(m.get_row(0).collect::<Vec<_>>(), [c(1, 1), c(2, -2)]);
+        assert_eq!(m.get_row(1).collect::<Vec<_>>(), [c(1, -1), c(3, 3)]);
+        assert_eq!(m.get_row(2).collect::<Vec<_>>(), [c(2, 2), c(3, -3)]);
+        assert_eq!(m.get_col(2).collect::<Vec<_>>(), [c(2, -2), c(3, 3)]);
+    }
+
+    #[test]
+    fn test_set_element() {
+        let mut m = UpTriVec(3, vec![c(0, 0); 3]);
+        m.set_element(0, 1, c(1, 2));
+        m.set_element(2, 1, c(3, 4));
+
+        assert_eq!(m.inner(), &[c(1, 2), c(0, 0), c(3, -4)]);
+        assert_eq!(m.get_element(2, 1), c(3, 4));
+    }
+
+    #[test]
+    fn test_real() {
+        let m = crate::Tri::<crate::SymmetricUpper, _>::new(3, vec![1.0, 2.0, 3.0]);
+
+        assert_eq!(HermitianUpperTri::get_element(&m, 2, 0), 2.0);
+        assert_eq!(
+            HermitianUpperTri::get_row(&m, 1).collect::<Vec<_>>(),
+            [1.0, 3.0]
+        );
+    }
+}
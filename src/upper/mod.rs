@@ -1,8 +1,16 @@
 //! Upper triangle traits.
 pub mod base;
 
+mod hermitian;
+// The baseline accessors keep explicit lifetimes.
+#[allow(clippy::needless_lifetimes)]
 mod simple;
+mod skew;
+// The baseline accessors keep explicit lifetimes.
+#[allow(clippy::needless_lifetimes)]
 mod symmetric;
 
+pub use hermitian::{HermitianUpperTri, HermitianUpperTriMut};
 pub use simple::{SimpleUpperTri, SimpleUpperTriMut};
+pub use skew::{SkewUpperTri, SkewUpperTriMut};
 pub use symmetric::{SymmetricUpperTri, SymmetricUpperTriMut};
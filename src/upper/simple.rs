@@ -1,8 +1,14 @@
 //! A simple upper triangle abstraction.
+use std::cmp::Ordering;
 use std::ops::DerefMut;
 
 use super::base;
-use crate::{Triangle, TriangleMut};
+use crate::diagonal::ConstDiagonal;
+use crate::display::TriDisplay;
+use crate::linalg::mds::{self, Mds};
+use crate::ops::{select_by_key, validate_len};
+use crate::stats::ToF64;
+use crate::{Col, Error, Pair, Row, SimpleUpper, Triangle, TriangleMut};
 
 /// A simple upper triangle collection.
 ///
@@ -10,11 +16,16 @@ use crate::{Triangle, TriangleMut};
 /// the diagonal.
 ///
 /// Any index outside of the upper triangle will cause a panic.
+///
+/// Accessors require a non-empty triangle, `n > 0`, with `tri_num(n - 1)` elements.
+/// Only `validate`, `validated` and `iter_triangle_indices` accept an empty axis.
 pub trait SimpleUpperTri<T>: Triangle<T> {
     /// Get a reference to an element.
-    fn get_element<'a>(&'a self, i: usize, j: usize) -> &'a T {
-        debug_assert!(i <= self.n() - 1);
-        debug_assert!(j <= self.n() - 1);
+    fn get_element<'a>(&'a self, i: impl Into<Row>, j: impl Into<Col>) -> &'a T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
 
         assert!(j != 0);
         assert!(i < j);
@@ -23,8 +34,15 @@ pub trait SimpleUpperTri<T>: Triangle<T> {
         &self.inner()[index]
     }
 
+    /// Get a reference to the element at a pair of indices.
+    fn get_pair(&self, pair: impl Into<Pair>) -> &T {
+        let Pair { i, j } = pair.into();
+
+        SimpleUpperTri::get_element(self, i, j)
+    }
+
     /// Get an iterator of references to elements of a row.
-    fn get_row<'a>(&'a self, i: usize) -> impl Iterator<Item = &'a T>
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = &'a T>
     where
         T: 'a,
     {
@@ -32,23 +50,27 @@ pub trait SimpleUpperTri<T>: Triangle<T> {
     }
 
     /// Get an iterator of references to elements of a col.
-    fn get_col<'a>(&'a self, i: usize) -> impl Iterator<Item = &'a T>
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = &'a T>
     where
         T: 'a,
     {
-        SimpleUpperTri::get_col_indices(self, i).map(|el| &self.inner()[el])
+        SimpleUpperTri::get_col_indices(self, j).map(|el| &self.inner()[el])
     }
 
     /// Get the first index of a row.
-    fn get_row_start_index(&self, i: usize) -> usize {
-        debug_assert!(i <= self.n() - 1);
+    fn get_row_start_index(&self, i: impl Into<Row>) -> usize {
+        let Row(i) = i.into();
+
+        debug_assert!(i < self.n());
 
         base::get_row_start_index(i, self.n() - 1)
     }
 
     /// Get the first index of a column.
-    fn get_col_start_index(&self, j: usize) -> usize {
-        debug_assert!(j <= self.n() - 1);
+    fn get_col_start_index(&self, j: impl Into<Col>) -> usize {
+        let Col(j) = j.into();
+
+        debug_assert!(j < self.n());
 
         assert!(j != 0);
 
@@ -56,15 +78,19 @@ pub trait SimpleUpperTri<T>: Triangle<T> {
     }
 
     /// Get all indices of a row.
-    fn get_row_indices<'a, 'b>(&'a self, i: usize) -> impl Iterator<Item = usize> + 'b {
-        debug_assert!(i <= self.n() - 1);
+    fn get_row_indices<'a, 'b>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = usize> + 'b {
+        let Row(i) = i.into();
+
+        debug_assert!(i < self.n());
 
         base::get_row_indices(i, self.n() - 1)
     }
 
     /// Get all indices of a column.
-    fn get_col_indices<'a, 'b>(&'a self, j: usize) -> impl Iterator<Item = usize> + 'b {
-        debug_assert!(j <= self.n() - 1);
+    fn get_col_indices<'a, 'b>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = usize> + 'b {
+        let Col(j) = j.into();
+
+        debug_assert!(j < self.n());
 
         assert!(j != 0);
 
@@ -73,7 +99,157 @@ pub trait SimpleUpperTri<T>: Triangle<T> {
 
     /// Iterate all `(i, j)` indices of the triangle.
     fn iter_triangle_indices<'a, 'b>(&'a self) -> impl Iterator<Item = (usize, usize)> + 'b {
-        base::iter_triangle_indices(self.n() - 1).map(|(i, j)| (i, j + 1))
+        base::iter_triangle_indices(self.n().saturating_sub(1)).map(|(i, j)| (i, j + 1))
+    }
+
+    /// Iterate all `(i, j)` indices and references to elements of the triangle,
+    /// in storage order.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        SimpleUpperTri::iter_triangle_indices(self)
+            .zip(self.inner().iter())
+            .map(|((i, j), el)| (i, j, el))
+    }
+
+    /// Get the indices of, and a reference to, the smallest element. Ignores
+    /// incomparable elements such as `NaN`, and returns the first of equal elements.
+    fn argmin<'a>(&'a self) -> Option<(usize, usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SimpleUpperTri::argmin_by_key(self, |el| el)
+    }
+
+    /// Get the indices of, and a reference to, the largest element. Ignores
+    /// incomparable elements such as `NaN`, and returns the first of equal elements.
+    fn argmax<'a>(&'a self) -> Option<(usize, usize, &'a T)>
+    where
+        T: PartialOrd + 'a,
+    {
+        SimpleUpperTri::argmax_by_key(self, |el| el)
+    }
+
+    /// Get the indices of, and a reference to, the element with the smallest key.
+    /// Ignores incomparable keys such as `NaN`, and returns the first of equal keys.
+    fn argmin_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        select_by_key(
+            SimpleUpperTri::iter(self),
+            |(_, _, el)| f(el),
+            Ordering::Less,
+        )
+    }
+
+    /// Get the indices of, and a reference to, the element with the largest key.
+    /// Ignores incomparable keys such as `NaN`, and returns the first of equal keys.
+    fn argmax_by_key<'a, K: PartialOrd>(
+        &'a self,
+        mut f: impl FnMut(&'a T) -> K,
+    ) -> Option<(usize, usize, &'a T)>
+    where
+        T: 'a,
+    {
+        select_by_key(
+            SimpleUpperTri::iter(self),
+            |(_, _, el)| f(el),
+            Ordering::Greater,
+        )
+    }
+
+    /// Embed the `n` points of a distance matrix in `k` dimensions by classical
+    /// multidimensional scaling.
+    ///
+    /// Double-centres the squared distances and scales the eigenvectors of the
+    /// `k` largest eigenvalues. Returns an [`Error::DimensionMismatch`] if `k`
+    /// exceeds `n`.
+    fn classical_mds(&self, k: usize) -> Result<Mds, Error>
+    where
+        T: ToF64,
+    {
+        let distances = SimpleUpperTri::iter(self).map(|(i, j, el)| (i, j, el.to_f64()));
+        mds::classical_mds(self.n(), distances, k)
+    }
+
+    /// Get a reference to an element without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `i < j < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_element_unchecked(&self, i: impl Into<Row>, j: impl Into<Col>) -> &T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let index = base::get_element_index(i, j - (i + 1), self.n() - 1);
+
+        self.inner().get_unchecked(index)
+    }
+
+    /// Get an iterator of references to elements of a row without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `i < n - 1`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_row_unchecked<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let Row(i) = i.into();
+
+        base::get_row_indices(i, self.n() - 1).map(|el| unsafe { self.inner().get_unchecked(el) })
+    }
+
+    /// Get an iterator of references to elements of a col without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `0 < j < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_col_unchecked<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let Col(j) = j.into();
+
+        base::get_col_indices(j - 1, self.n() - 1)
+            .map(|el| unsafe { self.inner().get_unchecked(el) })
+    }
+
+    /// Get a formatter adapter displaying the triangle matrix.
+    fn display(&self) -> TriDisplay<'_, SimpleUpper, Self, T>
+    where
+        Self: Sized,
+    {
+        TriDisplay::new(self)
+    }
+
+    /// Get a view of the triangle matrix with the constant `diagonal`, getting
+    /// full rows and columns of `n` elements.
+    fn with_diagonal(&self, diagonal: T) -> ConstDiagonal<'_, SimpleUpper, Self, T>
+    where
+        Self: Sized,
+        T: Default,
+    {
+        ConstDiagonal::new(self, diagonal)
+    }
+
+    /// Validate the length of the inner collection against the axis length, `n`.
+    fn validate(&self) -> Result<(), Error> {
+        validate_len(self.n(), self.inner().len())
+    }
+
+    /// Validate the triangle, returning it if the inner collection length
+    /// matches the axis length, `n`.
+    fn validated(self) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        SimpleUpperTri::validate(&self)?;
+        Ok(self)
     }
 }
 
@@ -84,15 +260,49 @@ where
     Self::Inner: DerefMut<Target = [T]>,
 {
     /// Get a mutable reference to an element.
-    fn get_element_mut<'a>(&'a mut self, i: usize, j: usize) -> &'a mut T {
-        debug_assert!(i <= self.n() - 1);
-        debug_assert!(j <= self.n() - 1);
+    fn get_element_mut<'a>(&'a mut self, i: impl Into<Row>, j: impl Into<Col>) -> &'a mut T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        debug_assert!(i < self.n());
+        debug_assert!(j < self.n());
 
         assert!(i < j);
 
         let index = base::get_element_index(i, j - (i + 1), self.n() - 1);
         &mut self.inner_mut()[index]
     }
+
+    /// Get a mutable reference to the element at a pair of indices.
+    fn get_pair_mut(&mut self, pair: impl Into<Pair>) -> &mut T {
+        let Pair { i, j } = pair.into();
+
+        SimpleUpperTriMut::get_element_mut(self, i, j)
+    }
+
+    /// Get a mutable reference to an element without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Requires `i < j < n`, and an inner collection of `tri_num(n - 1)` elements.
+    unsafe fn get_element_unchecked_mut(&mut self, i: impl Into<Row>, j: impl Into<Col>) -> &mut T {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        let index = base::get_element_index(i, j - (i + 1), self.n() - 1);
+
+        self.inner_mut().get_unchecked_mut(index)
+    }
+
+    /// Iterate all `(i, j)` indices and mutable references to elements of the
+    /// triangle, in storage order.
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (usize, usize, &'a mut T)>
+    where
+        T: 'a,
+    {
+        base::iter_triangle_indices(self.n().saturating_sub(1))
+            .map(|(i, j)| (i, j + 1))
+            .zip(self.inner_mut().iter_mut())
+            .map(|((i, j), el)| (i, j, el))
+    }
 }
 
 impl<T, U: Triangle<T> + TriangleMut<T>> SimpleUpperTriMut<T> for U where
@@ -105,8 +315,8 @@ mod tests {
 
     mod upper_triangle {
 
+        use crate::{Col, Error, Pair, Row, Triangle, TriangleMut};
         use crate::{SimpleUpperTri, SimpleUpperTriMut};
-        use crate::{Triangle, TriangleMut};
 
         struct UpTriVec(usize, Vec<usize>);
 
@@ -283,6 +493,9 @@ mod tests {
 
         #[test]
         fn test_iter_triangle_indices() {
+            assert_eq!(UpTriVec(0, Vec::new()).iter_triangle_indices().count(), 0);
+            assert_eq!(UpTriVec(1, Vec::new()).iter_triangle_indices().count(), 0);
+
             let n = 5;
             let m = UpTriVec(n, Vec::new());
 
@@ -294,5 +507,214 @@ mod tests {
                                         (3, 4),
             ]);
         }
+
+        #[test]
+        fn test_validate() {
+            assert_eq!(UpTriVec(5, vec![0; 10]).validate(), Ok(()));
+            assert_eq!(UpTriVec(1, vec![]).validate(), Ok(()));
+            assert_eq!(UpTriVec(0, vec![]).validate(), Err(Error::EmptyAxis));
+            assert_eq!(
+                UpTriVec(4, vec![0; 10]).validate(),
+                Err(Error::InvalidLength {
+                    expected: 6,
+                    actual: 10
+                })
+            );
+
+            assert!(UpTriVec(5, vec![0; 10]).validated().is_ok());
+            assert!(UpTriVec(5, vec![0; 9]).validated().is_err());
+        }
+
+        #[test]
+        fn test_get_element_unchecked() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let m = UpTriVec(n, v);
+
+            for (i, j) in m.iter_triangle_indices() {
+                assert_eq!(
+                    unsafe { m.get_element_unchecked(i, j) },
+                    m.get_element(i, j)
+                );
+            }
+        }
+
+        #[test]
+        fn test_get_element_unchecked_mut() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let mut m = UpTriVec(n, v);
+            let pairs = m.iter_triangle_indices().collect::<Vec<_>>();
+
+            for (k, &(i, j)) in pairs.iter().enumerate() {
+                unsafe { *m.get_element_unchecked_mut(i, j) = k + 10 };
+                assert_eq!(*m.get_element(i, j), k + 10);
+            }
+        }
+
+        #[test]
+        fn test_get_row_unchecked() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let m = UpTriVec(n, v);
+
+            for i in 0..n - 1 {
+                assert!(unsafe { m.get_row_unchecked(i) }.eq(m.get_row(i)));
+            }
+        }
+
+        #[test]
+        fn test_get_col_unchecked() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let m = UpTriVec(n, v);
+
+            for j in 1..n {
+                assert!(unsafe { m.get_col_unchecked(j) }.eq(m.get_col(j)));
+            }
+        }
+
+        #[test]
+        fn test_row_col_indices() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let m = UpTriVec(n, v);
+
+            assert_eq!(*m.get_element(Row(1), Col(3)), 5);
+            assert_eq!(*m.get_element(Row(1), 3), 5);
+            assert_eq!(m.get_row(Row(1)).cloned().collect::<Vec<_>>(), [4, 5, 6]);
+            assert_eq!(m.get_col(Col(3)).cloned().collect::<Vec<_>>(), [2, 5, 7]);
+        }
+
+        #[test]
+        fn test_pair() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let mut m = UpTriVec(n, v);
+
+            assert_eq!(*m.get_pair((1, 3)), 5);
+            assert_eq!(*m.get_pair(Pair::upper(3, 1)), 5);
+
+            *m.get_pair_mut(Pair::new(Row(2), Col(4))) = 10;
+            assert_eq!(*m.get_element(2, 4), 10);
+        }
+
+        #[test]
+        fn test_iter() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let m = UpTriVec(n, v);
+
+            assert_eq!(m.iter().count(), 10);
+            for (k, (i, j, el)) in m.iter().enumerate() {
+                assert_eq!(*el, k);
+                assert_eq!(m.get_element(i, j), el);
+            }
+        }
+
+        #[test]
+        fn test_iter_mut() {
+            #[rustfmt::skip]
+            let v = vec![
+                0, 1, 2, 3,
+                   4, 5, 6,
+                      7, 8,
+                         9,
+            ];
+            let n = 5;
+            let mut m = UpTriVec(n, v);
+
+            for (i, j, el) in m.iter_mut() {
+                *el = i * 10 + j;
+            }
+            for (i, j) in m.iter_triangle_indices() {
+                assert_eq!(*m.get_element(i, j), i * 10 + j);
+            }
+        }
+
+        #[test]
+        fn test_argmin_argmax() {
+            #[rustfmt::skip]
+            let v = vec![
+                3, 1, 2, 3,
+                   4, 1, 6,
+                      7, 9,
+                         9,
+            ];
+            let n = 5;
+            let m = UpTriVec(n, v);
+
+            assert_eq!(m.argmin(), Some((0, 2, &1)));
+            assert_eq!(m.argmax(), Some((2, 4, &9)));
+            assert_eq!(m.argmin_by_key(|el| el.abs_diff(5)), Some((1, 2, &4)));
+            assert_eq!(m.argmax_by_key(|el| el % 4), Some((0, 1, &3)));
+        }
+
+        #[test]
+        fn test_argmin_nan() {
+            let m = crate::Tri::<crate::SimpleUpper, _>::new(3, vec![f64::NAN, 2.0, 1.0]);
+
+            assert_eq!(m.argmin(), Some((1, 2, &1.0)));
+            assert_eq!(m.argmax(), Some((0, 2, &2.0)));
+
+            let m = crate::Tri::<crate::SimpleUpper, _>::new(2, vec![f64::NAN]);
+
+            assert_eq!(m.argmin(), None);
+        }
+
+        #[test]
+        fn test_classical_mds() {
+            // Points at `0`, `1` and `3` on a line.
+            let m = crate::Tri::<crate::SimpleUpper, _>::new(3, vec![1_usize, 3, 2]);
+            let mds = m.classical_mds(1).unwrap();
+            let x = &mds.embedding;
+
+            assert!(((x[1] - x[0]).abs() - 1.0).abs() < 1e-10);
+            assert!(((x[2] - x[0]).abs() - 3.0).abs() < 1e-10);
+            assert!(((x[2] - x[1]).abs() - 2.0).abs() < 1e-10);
+            assert!((mds.explained_variance[0] - 1.0).abs() < 1e-10);
+        }
     }
 }
@@ -0,0 +1,169 @@
+//! A skew-symmetric upper triangle matrix abstraction.
+use std::ops::{DerefMut, Neg};
+
+use super::symmetric::{SymmetricUpperTri, SymmetricUpperTriMut};
+use crate::ops::{mirrored_dense, oriented, oriented_row};
+use crate::{Col, Row, SimpleUpper, Triangle, TriangleMut};
+
+/// A skew-symmetric upper triangle collection.
+///
+/// Contains `tri_num(n)` elements with `n - 1` rows and columns to account for
+/// the diagonal.
+///
+/// For all indices `i` and `j` where `i != j`, the element `(j, i)` is the
+/// negation of the element `(i, j)`. Elements are stored in the orientation
+/// `i < j`, and returned by value. Indexing, iteration and validation of the
+/// stored elements are provided by [`SymmetricUpperTri`].
+pub trait SkewUpperTri<T>: SymmetricUpperTri<T> {
+    /// Get an element, negated where `j < i`.
+    fn get_element(&self, i: impl Into<Row>, j: impl Into<Col>) -> T
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        oriented::<SimpleUpper, _>(i, j, *SymmetricUpperTri::get_element(self, i, j), T::neg)
+    }
+
+    /// Get an iterator of the signed elements of a row.
+    fn get_row<'a>(&'a self, i: impl Into<Row>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Neg<Output = T> + 'a,
+    {
+        let Row(i) = i.into();
+
+        oriented_row::<SimpleUpper, _>(self.n(), i, SymmetricUpperTri::get_row(self, i), T::neg)
+    }
+
+    /// Get an iterator of the signed elements of a column.
+    fn get_col<'a>(&'a self, j: impl Into<Col>) -> impl Iterator<Item = T> + 'a
+    where
+        T: Copy + Neg<Output = T> + 'a,
+    {
+        let Col(j) = j.into();
+
+        SkewUpperTri::get_row(self, j).map(T::neg)
+    }
+
+    /// Export the full matrix as a row-major `n × n` collection, with zero
+    /// (`T::default()`) diagonal elements.
+    fn to_dense(&self) -> Vec<T>
+    where
+        T: Copy + Default + Neg<Output = T>,
+    {
+        let elements = SymmetricUpperTri::iter(self).map(|(i, j, el)| (i, j, *el));
+        mirrored_dense(self.n(), elements, T::neg)
+    }
+}
+
+impl<T, U: Triangle<T>> SkewUpperTri<T> for U {}
+
+pub trait SkewUpperTriMut<T>: SymmetricUpperTriMut<T>
+where
+    Self::Inner: DerefMut<Target = [T]>,
+{
+    /// Set an element, storing the negation of `value` where `j < i`.
+    fn set_element(&mut self, i: impl Into<Row>, j: impl Into<Col>, value: T)
+    where
+        T: Neg<Output = T>,
+    {
+        let (Row(i), Col(j)) = (i.into(), j.into());
+
+        *SymmetricUpperTriMut::get_element_mut(self, i, j) =
+            oriented::<SimpleUpper, _>(i, j, value, T::neg);
+    }
+}
+
+impl<T, U: Triangle<T> + TriangleMut<T>> SkewUpperTriMut<T> for U where
+    Self::Inner: DerefMut<Target = [T]>
+{
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{SkewUpperTri, SkewUpperTriMut};
+    use crate::{Triangle, TriangleMut};
+
+    struct UpTriVec(usize, Vec<i32>);
+
+    impl Triangle<i32> for UpTriVec {
+        type Inner = Vec<i32>;
+
+        fn n(&self) -> usize {
+            self.0
+        }
+
+        fn inner(&self) -> &Vec<i32> {
+            &self.1
+        }
+    }
+
+    impl TriangleMut<i32> for UpTriVec {
+        fn inner_mut(&mut self) -> &mut Vec<i32> {
+            &mut self.1
+        }
+    }
+
+    #[test]
+    fn test_get_element() {
+        #[rustfmt::skip]
+        let v = vec![
+            1, 2, 3,
+               4, 5,
+                  6,
+        ];
+
+        let m = UpTriVec(4, v);
+
+        assert_eq!(m.get_element(0, 1), 1);
+        assert_eq!(m.get_element(1, 0), -1);
+        assert_eq!(m.get_element(1, 3), 5);
+        assert_eq!(m.get_element(3, 1), -5);
+        assert_eq!(m.get_element(2, 3), 6);
+        assert_eq!(m.get_element(3, 2), -6);
+    }
+
+    #[test]
+    fn test_get_row_col() {
+        #[rustfmt::skip]
+        let v = vec![
+            1, 2, 3,
+               4, 5,
+                  6,
+        ];
+
+        let m = UpTriVec(4, v);
+
+        assert_eq!(m.get_row(0).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(m.get_row(1).collect::<Vec<_>>(), [-1, 4, 5]);
+        assert_eq!(m.get_row(2).collect::<Vec<_>>(), [-2, -4, 6]);
+        assert_eq!(m.get_row(3).collect::<Vec<_>>(), [-3, -5, -6]);
+        assert_eq!(m.get_col(1).collect::<Vec<_>>(), [1, -4, -5]);
+        assert_eq!(m.get_col(3).collect::<Vec<_>>(), [3, 5, 6]);
+    }
+
+    #[test]
+    fn test_set_element() {
+        let mut m = UpTriVec(4, vec![0; 6]);
+        m.set_element(0, 2, 7);
+        m.set_element(3, 1, 8);
+
+        assert_eq!(m.inner(), &[0, 7, 0, 0, -8, 0]);
+        assert_eq!(m.get_element(2, 0), -7);
+        assert_eq!(m.get_element(3, 1), 8);
+    }
+
+    #[test]
+    fn test_to_dense() {
+        let m = UpTriVec(3, vec![1, 2, 3]);
+
+        #[rustfmt::skip]
+        let expected = [
+             0,  1, 2,
+            -1,  0, 3,
+            -2, -3, 0,
+        ];
+        assert_eq!(m.to_dense(), expected);
+    }
+}